@@ -0,0 +1,145 @@
+use crate::errors::{run_command_with_timeout, ServiceProbeError, ServiceProbeErrorKind};
+use crate::ServiceProbe;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const SYSTEMD_UNIT_NAME: &str = "fs-jira.service";
+
+pub fn probe_service() -> Result<ServiceProbe, ServiceProbeError> {
+    let unit_path = resolve_unit_path();
+    let installed = unit_path.exists();
+    let (config_path, mountpoint) = if installed {
+        let content = fs::read_to_string(&unit_path).map_err(|error| ServiceProbeError {
+            kind: ServiceProbeErrorKind::ParseError,
+            message: format!(
+                "failed to read systemd unit at {}: {error}",
+                unit_path.display()
+            ),
+        })?;
+        parse_exec_start(&content)
+    } else {
+        (None, None)
+    };
+
+    let mut command = Command::new("systemctl");
+    command
+        .args(["--user", "is-active", SYSTEMD_UNIT_NAME])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let output = run_command_with_timeout(command, Duration::from_secs(2))?;
+    let running = output.status_ok;
+
+    Ok(ServiceProbe {
+        installed,
+        running,
+        config_path,
+        mountpoint,
+    })
+}
+
+pub fn start_service() -> Result<(), ServiceProbeError> {
+    let unit_path = resolve_unit_path();
+    if !unit_path.exists() {
+        return Err(ServiceProbeError {
+            kind: ServiceProbeErrorKind::NotInstalled,
+            message: format!(
+                "systemd unit not found at {}; install service first",
+                unit_path.display()
+            ),
+        });
+    }
+
+    let mut start = Command::new("systemctl");
+    start
+        .args(["--user", "start", SYSTEMD_UNIT_NAME])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let start_output = run_command_with_timeout(start, Duration::from_secs(5))?;
+    if start_output.status_ok {
+        return Ok(());
+    }
+
+    let mut status = Command::new("systemctl");
+    status
+        .args(["--user", "status", SYSTEMD_UNIT_NAME])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let status_output = run_command_with_timeout(status, Duration::from_secs(2))?;
+
+    Err(ServiceProbeError {
+        kind: ServiceProbeErrorKind::Unreachable,
+        message: format!(
+            "failed to start {}: {}{}",
+            SYSTEMD_UNIT_NAME, start_output.stderr, status_output.stdout
+        ),
+    })
+}
+
+fn resolve_unit_path() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_default();
+    PathBuf::from(home)
+        .join(".config")
+        .join("systemd")
+        .join("user")
+        .join(SYSTEMD_UNIT_NAME)
+}
+
+pub fn parse_exec_start(unit_content: &str) -> (Option<String>, Option<String>) {
+    let Some(exec_line) = unit_content
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with("ExecStart="))
+    else {
+        return (None, None);
+    };
+
+    let args: Vec<&str> = exec_line
+        .trim_start_matches("ExecStart=")
+        .split_whitespace()
+        .collect();
+
+    let mut config_path = None;
+    for (idx, token) in args.iter().enumerate() {
+        if *token == "--config" {
+            config_path = args.get(idx + 1).map(|s| s.to_string());
+        }
+    }
+
+    let mountpoint = args
+        .iter()
+        .rev()
+        .find(|token| !token.starts_with('-'))
+        .map(|s| s.to_string());
+
+    (config_path, mountpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_systemd_exec_start() {
+        let content = r#"
+[Unit]
+Description=fs-jira mount
+
+[Service]
+ExecStart=/usr/local/bin/fs-jira --config /tmp/config.toml /tmp/fs-jira
+Restart=on-failure
+
+[Install]
+WantedBy=default.target
+"#;
+
+        let (config, mountpoint) = parse_exec_start(content);
+        assert_eq!(config.as_deref(), Some("/tmp/config.toml"));
+        assert_eq!(mountpoint.as_deref(), Some("/tmp/fs-jira"));
+    }
+}
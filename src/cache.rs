@@ -1,14 +1,119 @@
+pub mod backend;
+mod lmdb;
 pub mod persistent;
 
-use std::collections::HashMap;
-use std::path::Path;
-use std::sync::{Arc, Mutex, MutexGuard};
-use std::time::{Duration, Instant};
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
 
 use crate::jira::IssueRef;
 use crate::logging;
 use crate::metrics::Metrics;
-use persistent::{PersistentCache, TicketIndexRow};
+use backend::{PersistenceBackend, PersistenceBackendKind, PersistenceError};
+use persistent::TicketIndexRow;
+
+const SYNC_CHECKPOINT_BLOB_KEY: &str = "sync_checkpoint";
+
+/// Builds the per-project blob key under which `warmup::reconcile_projects`
+/// stores its short-circuit digest; see [`InMemoryCache::get_reconcile_digest`].
+fn reconcile_digest_blob_key(project: &str) -> String {
+    format!("reconcile_digest:{project}")
+}
+
+fn unix_epoch_seconds() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_else(|_| {
+            logging::warn("system clock before unix epoch; using fallback timestamp 0");
+            0
+        })
+}
+
+/// Blob key under which the whole [`SyncTask`] history ring is mirrored, so
+/// it survives a restart; see [`InMemoryCache::enqueue_sync_task`].
+const SYNC_TASKS_BLOB_KEY: &str = "sync_tasks";
+
+/// Default bound on the historical sync-task ring kept by
+/// [`InMemoryCache::enqueue_sync_task`]; override via
+/// [`InMemoryCache::set_sync_task_retention`].
+const DEFAULT_SYNC_TASK_RETENTION: usize = 50;
+
+/// Default number of retained `.history/` snapshots per issue.
+const DEFAULT_HISTORY_MAX_VERSIONS: usize = 20;
+
+/// Bound on each [`CacheEvent`] subscriber channel: a subscriber that falls
+/// behind silently misses events rather than blocking cache writers.
+const SUBSCRIBER_CHANNEL_CAPACITY: usize = 256;
+
+/// What happened to an issue's cached value; see [`InMemoryCache::subscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEventKind {
+    /// The cached markdown was replaced with genuinely new content.
+    Refreshed,
+    /// A refresh failed and the previous value was served instead.
+    StaleServed,
+    /// The entry was dropped from memory to stay under `max_in_memory_bytes`
+    /// (still readable via persistence, if configured).
+    Evicted,
+}
+
+#[derive(Debug, Clone)]
+/// Notification that an issue's cached value changed state, so other
+/// subsystems (a FUSE inode-invalidation layer, a background prefetcher, a
+/// status view) can react without polling `cached_issue_len`.
+pub struct CacheEvent {
+    pub issue_key: String,
+    pub kind: CacheEventKind,
+    pub source_updated: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Compact, resumable snapshot of in-flight sync progress for one project.
+pub struct SyncCheckpoint {
+    pub project: String,
+    pub start_at: usize,
+    pub remaining_budget: usize,
+    pub cached_this_run: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// What kind of pass a [`SyncTask`] represents.
+pub enum SyncTaskKind {
+    Full,
+    Incremental,
+    Reconcile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Lifecycle state of a [`SyncTask`]. `Running` is the only non-terminal
+/// value; the rest are set once by [`InMemoryCache::finish_sync_task`].
+pub enum SyncTaskStatus {
+    Running,
+    Succeeded,
+    Failed,
+    Partial,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Record of one `sync_issues`/`seed_workspace_listings` invocation, kept in
+/// a bounded history ring so the FUSE layer can surface a `.sync-status`
+/// virtual file; see [`InMemoryCache::enqueue_sync_task`].
+pub struct SyncTask {
+    pub id: u64,
+    pub workspaces: Vec<String>,
+    pub kind: SyncTaskKind,
+    pub status: SyncTaskStatus,
+    pub enqueued_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+    pub errors: Vec<String>,
+}
 
 /// Batch row for issue markdown cache upserts.
 pub type IssueCacheRow = (String, Vec<u8>, Option<String>);
@@ -31,55 +136,198 @@ pub struct ProjectIssuesSnapshot {
     pub is_stale: bool,
 }
 
+#[derive(Debug, Clone)]
+/// Marks `issue_key` as observed missing from `project`'s JQL scope (deleted
+/// in Jira, or moved out of the filter) as of `deleted_at` (unix seconds).
+/// Recorded by a reconciliation pass rather than acted on immediately, so a
+/// merely-unchanged incremental sync result (which also omits most issues)
+/// can't be mistaken for a mass deletion; see [`InMemoryCache::tombstone_issue`].
+pub struct IssueTombstone {
+    pub issue_key: String,
+    pub deleted_at: i64,
+}
+
 #[derive(Debug, Clone)]
 struct CachedIssue {
     markdown: Vec<u8>,
+    /// Sequence number from `InMemoryCache::access_seq`, bumped on every
+    /// read and write, so eviction can find the coldest entry by comparing
+    /// this instead of `cached_at` (which tracks freshness, not recency).
+    last_access: u64,
+    /// Hex-encoded BLAKE3 over `markdown`; see [`backend::content_hash`].
+    /// The authoritative change signal on refresh — Jira's `updated` can
+    /// change without the rendered markdown changing, and is sometimes
+    /// absent entirely.
+    content_hash: String,
 }
 
+/// Shared slot a single-flight fetch result is published into: `None` while
+/// the leader is still fetching, `Some(result)` once it's done. Type-erased
+/// to `dyn Any` in the cache's map since each call site's error type `E`
+/// differs; downcast back to the caller's own `E` on lookup.
+type InflightSlot<E> = (Mutex<Option<Result<(Vec<u8>, Option<String>), E>>>, Condvar);
+
 #[derive(Debug)]
-/// In-memory issue cache with optional SQLite persistence.
+/// In-memory issue cache with optional durable persistence, behind a
+/// pluggable [`PersistenceBackend`].
 pub struct InMemoryCache {
     project_ttl: Duration,
     issue_ttl: Duration,
     project_issues: Mutex<HashMap<String, CacheEntry<Vec<IssueRef>>>>,
+    query_issues: Mutex<HashMap<String, CacheEntry<Vec<IssueRef>>>>,
     issue_markdown: Mutex<HashMap<String, CacheEntry<CachedIssue>>>,
-    persistent: Option<PersistentCache>,
+    inflight_fetches: Mutex<HashMap<String, Arc<dyn Any + Send + Sync>>>,
+    persistent: Option<Box<dyn PersistenceBackend>>,
+    db_path: Option<PathBuf>,
     metrics: Arc<Metrics>,
+    /// Resident `issue_markdown` byte budget; `None` means unbounded. Evicted
+    /// entries are always re-fetchable (via `fetch`, or re-hydrated from
+    /// `persistent` when configured), so eviction only ever costs a refetch,
+    /// never data.
+    max_in_memory_bytes: Option<u64>,
+    resident_bytes: AtomicU64,
+    access_seq: AtomicU64,
+    /// Fan-out list of live subscriber channels; see [`Self::subscribe`].
+    subscribers: Mutex<Vec<flume::Sender<CacheEvent>>>,
+    /// Issues a reconciliation pass observed missing from their project's
+    /// JQL scope, keyed by project, pending [`Self::gc_tombstones`]; see
+    /// [`IssueTombstone`].
+    project_tombstones: Mutex<HashMap<String, Vec<IssueTombstone>>>,
+    /// Bounded ring of recent sync tasks, mirrored to persistence under
+    /// `SYNC_TASKS_BLOB_KEY` on every mutation and lazily hydrated back from
+    /// there the first time a read method runs in a fresh process; see
+    /// [`Self::enqueue_sync_task`].
+    sync_tasks: Mutex<VecDeque<SyncTask>>,
+    next_sync_task_id: AtomicU64,
+    sync_task_retention: AtomicUsize,
+    sync_tasks_hydrated: AtomicBool,
 }
 
 impl InMemoryCache {
-    /// Creates an in-memory cache without persistence.
-    pub fn new(project_ttl: Duration, issue_ttl: Duration, metrics: Arc<Metrics>) -> Self {
+    /// Creates an in-memory cache without persistence. `max_in_memory_bytes`
+    /// caps the resident `issue_markdown` byte total; `None` leaves it
+    /// unbounded.
+    pub fn new(
+        project_ttl: Duration,
+        issue_ttl: Duration,
+        max_in_memory_bytes: Option<u64>,
+        metrics: Arc<Metrics>,
+    ) -> Self {
         Self {
             project_ttl,
             issue_ttl,
             project_issues: Mutex::new(HashMap::new()),
+            query_issues: Mutex::new(HashMap::new()),
             issue_markdown: Mutex::new(HashMap::new()),
+            inflight_fetches: Mutex::new(HashMap::new()),
             persistent: None,
+            db_path: None,
             metrics,
+            max_in_memory_bytes,
+            resident_bytes: AtomicU64::new(0),
+            access_seq: AtomicU64::new(0),
+            subscribers: Mutex::new(Vec::new()),
+            project_tombstones: Mutex::new(HashMap::new()),
+            sync_tasks: Mutex::new(VecDeque::new()),
+            next_sync_task_id: AtomicU64::new(1),
+            sync_task_retention: AtomicUsize::new(DEFAULT_SYNC_TASK_RETENTION),
+            sync_tasks_hydrated: AtomicBool::new(false),
         }
     }
 
-    /// Creates an in-memory cache backed by SQLite persistence.
+    /// Creates an in-memory cache backed by durable persistence, using
+    /// whichever storage engine `backend_kind` selects. `max_in_memory_bytes`
+    /// caps the resident `issue_markdown` byte total; `None` leaves it
+    /// unbounded. Entries evicted to stay under budget are transparently
+    /// re-hydrated from persistence on the next read. `compression_level`
+    /// controls the zstd level the backend applies to persisted markdown and
+    /// comment sidecars (see [`backend::DEFAULT_COMPRESSION_LEVEL`]); the
+    /// in-memory `issue_markdown` cache itself always stays uncompressed.
+    /// `min_read_conn`/`max_read_conn` size the SQLite backend's pooled read
+    /// connections (see [`backend::DEFAULT_MIN_READ_CONN`] and
+    /// [`backend::DEFAULT_MAX_READ_CONN`]) and are ignored by LMDB.
+    /// `persistent_max_bytes` caps the SQLite backend's on-disk footprint
+    /// (see `PersistentCache::enforce_cache_budget`); `None` leaves it
+    /// unbounded, and it's ignored by LMDB.
     ///
     /// # Errors
-    /// Returns [`rusqlite::Error`] when opening or initializing persistence fails.
+    /// Returns [`PersistenceError`] when opening or initializing the backend fails.
     pub fn with_persistence(
         project_ttl: Duration,
         issue_ttl: Duration,
+        max_in_memory_bytes: Option<u64>,
+        backend_kind: PersistenceBackendKind,
         db_path: &Path,
+        compression_level: i32,
+        min_read_conn: u32,
+        max_read_conn: u32,
+        persistent_max_bytes: Option<u64>,
         metrics: Arc<Metrics>,
-    ) -> Result<Self, rusqlite::Error> {
+    ) -> Result<Self, PersistenceError> {
         Ok(Self {
             project_ttl,
             issue_ttl,
             project_issues: Mutex::new(HashMap::new()),
+            query_issues: Mutex::new(HashMap::new()),
             issue_markdown: Mutex::new(HashMap::new()),
-            persistent: Some(PersistentCache::new(db_path)?),
+            inflight_fetches: Mutex::new(HashMap::new()),
+            persistent: Some(backend::open(
+                backend_kind,
+                db_path,
+                compression_level,
+                min_read_conn,
+                max_read_conn,
+                persistent_max_bytes,
+                metrics.clone(),
+            )?),
+            db_path: Some(db_path.to_path_buf()),
             metrics,
+            max_in_memory_bytes,
+            resident_bytes: AtomicU64::new(0),
+            access_seq: AtomicU64::new(0),
+            subscribers: Mutex::new(Vec::new()),
+            project_tombstones: Mutex::new(HashMap::new()),
+            sync_tasks: Mutex::new(VecDeque::new()),
+            next_sync_task_id: AtomicU64::new(1),
+            sync_task_retention: AtomicUsize::new(DEFAULT_SYNC_TASK_RETENTION),
+            sync_tasks_hydrated: AtomicBool::new(false),
         })
     }
 
+    /// Returns the directory holding the persistent cache database, if any,
+    /// for sidecar files (e.g. the inode allocation journal) that should
+    /// live alongside it.
+    pub fn persistent_dir(&self) -> Option<PathBuf> {
+        self.db_path
+            .as_ref()
+            .and_then(|path| path.parent())
+            .map(PathBuf::from)
+    }
+
+    /// Subscribes to [`CacheEvent`]s (an issue refreshed, stale-served, or
+    /// evicted), so other subsystems can react the moment cached content
+    /// actually changes instead of polling `cached_issue_len`. The channel
+    /// is bounded: a subscriber that falls behind silently misses events
+    /// rather than blocking cache writers.
+    pub fn subscribe(&self) -> flume::Receiver<CacheEvent> {
+        let (tx, rx) = flume::bounded(SUBSCRIBER_CHANNEL_CAPACITY);
+        self.subscribers.lock_or_recover("subscribers").push(tx);
+        rx
+    }
+
+    /// Fans `event` out to every live subscriber, dropping any whose
+    /// receiver has hung up. A subscriber whose channel is merely full just
+    /// misses this event — see [`Self::subscribe`].
+    fn broadcast(&self, event: CacheEvent) {
+        let mut subscribers = self.subscribers.lock_or_recover("subscribers");
+        subscribers.retain(|tx| {
+            !matches!(
+                tx.try_send(event.clone()),
+                Err(flume::TrySendError::Disconnected(_))
+            )
+        });
+    }
+
     /// Gets project issues from cache or via `fetch`, then caches fresh values.
     pub fn get_project_issues<F, E>(&self, project: &str, fetch: F) -> Result<Vec<IssueRef>, E>
     where
@@ -147,7 +395,83 @@ impl InMemoryCache {
             .insert(project.to_string(), entry);
     }
 
+    /// Drops an issue no longer present in Jira from the project listing,
+    /// the in-memory markdown cache, and persistence (markdown, ticket
+    /// index, comment sidecars, history versions).
+    pub fn remove_issue_everywhere(&self, project: &str, issue_key: &str) {
+        if let Some(mut entry) = self
+            .project_issues
+            .lock_or_recover("project_issues")
+            .get(project)
+            .cloned()
+        {
+            entry.value.retain(|issue_ref| issue_ref.key != issue_key);
+            self.project_issues
+                .lock_or_recover("project_issues")
+                .insert(project.to_string(), entry);
+        }
+
+        if let Some(removed) = self
+            .issue_markdown
+            .lock_or_recover("issue_markdown")
+            .remove(issue_key)
+        {
+            self.release_resident_bytes(removed.value.markdown.len() as u64);
+        }
+
+        if let Some(persistent) = &self.persistent {
+            if let Err(err) = persistent.remove_issue(issue_key) {
+                logging::warn(format!(
+                    "failed to remove deleted issue {} from persistence: {}",
+                    issue_key, err
+                ));
+            }
+        }
+    }
+
+    /// Records that a reconciliation pass observed `issue_key` missing from
+    /// `project`'s JQL scope, replacing any prior tombstone for the same
+    /// issue. Callers evict the issue's cached content themselves (e.g. via
+    /// [`Self::remove_issue_everywhere`]) — this only tracks *that* the
+    /// deletion happened and *when*, for [`Self::gc_tombstones`].
+    pub fn tombstone_issue(&self, project: &str, issue_key: &str, deleted_at: i64) {
+        let mut tombstones = self.project_tombstones.lock_or_recover("project_tombstones");
+        let entries = tombstones.entry(project.to_string()).or_default();
+        entries.retain(|tombstone| tombstone.issue_key != issue_key);
+        entries.push(IssueTombstone {
+            issue_key: issue_key.to_string(),
+            deleted_at,
+        });
+    }
+
+    /// Lists tombstones recorded for `project`, oldest first.
+    pub fn list_tombstones(&self, project: &str) -> Vec<IssueTombstone> {
+        let mut entries = self
+            .project_tombstones
+            .lock_or_recover("project_tombstones")
+            .get(project)
+            .cloned()
+            .unwrap_or_default();
+        entries.sort_by_key(|tombstone| tombstone.deleted_at);
+        entries
+    }
+
+    /// Forgets tombstones for `project` older than `retention_secs` relative
+    /// to `now` (unix seconds), bounding how long the tombstone list grows.
+    /// Returns how many were forgotten.
+    pub fn gc_tombstones(&self, project: &str, retention_secs: i64, now: i64) -> usize {
+        let mut tombstones = self.project_tombstones.lock_or_recover("project_tombstones");
+        let Some(entries) = tombstones.get_mut(project) else {
+            return 0;
+        };
+        let before = entries.len();
+        entries.retain(|tombstone| now.saturating_sub(tombstone.deleted_at) < retention_secs);
+        before - entries.len()
+    }
+
     /// Returns issue markdown and serves stale values on refresh failure.
+    /// Concurrent misses for the same `issue_key` are coalesced via
+    /// [`Self::fetch_single_flight`] so only one caller actually runs `fetch`.
     pub fn get_issue_markdown_stale_safe<F, E>(
         &self,
         issue_key: &str,
@@ -155,7 +479,7 @@ impl InMemoryCache {
     ) -> Result<Vec<u8>, E>
     where
         F: FnOnce() -> Result<(Vec<u8>, Option<String>), E>,
-        E: Clone,
+        E: Clone + Send + Sync + 'static,
     {
         let now = Instant::now();
         let existing = self
@@ -166,6 +490,7 @@ impl InMemoryCache {
 
         if let Some(entry) = &existing {
             if now.duration_since(entry.cached_at) < entry.ttl {
+                self.touch_issue_access(issue_key);
                 self.metrics.inc_cache_hit();
                 return Ok(entry.value.markdown.clone());
             }
@@ -177,42 +502,56 @@ impl InMemoryCache {
                     let hydrated = CacheEntry {
                         value: CachedIssue {
                             markdown: issue.markdown.clone(),
+                            last_access: self.next_access_seq(),
+                            content_hash: issue.content_hash,
                         },
                         cached_at: now,
                         ttl: self.issue_ttl,
                         source_updated: issue.updated,
                     };
-                    self.issue_markdown
-                        .lock_or_recover("issue_markdown")
-                        .insert(issue_key.to_string(), hydrated);
+                    self.insert_issue_markdown(issue_key, hydrated);
                     self.metrics.inc_cache_hit();
                     return Ok(issue.markdown);
                 }
             }
         }
 
-        self.metrics.inc_cache_miss();
-        let fetched = fetch();
+        let fetched = self.fetch_single_flight(issue_key, fetch);
 
         let (fresh_markdown, fresh_updated) = match fetched {
             Ok(value) => value,
             Err(err) => {
                 if let Some(entry) = existing {
                     self.metrics.inc_stale_served();
+                    self.broadcast(CacheEvent {
+                        issue_key: issue_key.to_string(),
+                        kind: CacheEventKind::StaleServed,
+                        source_updated: entry.source_updated.clone(),
+                    });
                     return Ok(entry.value.markdown);
                 }
                 return Err(err);
             }
         };
 
+        let fresh_hash = backend::content_hash(&fresh_markdown);
+
         if let Some(mut entry) = self
             .issue_markdown
             .lock_or_recover("issue_markdown")
             .get(issue_key)
             .cloned()
         {
-            if entry.source_updated == fresh_updated {
+            // Compare content hashes, not `updated`: Jira's `updated`
+            // timestamp can change without the rendered markdown actually
+            // changing (and is sometimes absent), so the hash is the
+            // authoritative change signal. When unchanged, only bump
+            // `cached_at` — skip both the in-memory replacement and the
+            // `persistent.upsert_issue` call to avoid needless write
+            // amplification on periodic resyncs.
+            if entry.value.content_hash == fresh_hash {
                 entry.cached_at = now;
+                entry.value.last_access = self.next_access_seq();
                 self.issue_markdown
                     .lock_or_recover("issue_markdown")
                     .insert(issue_key.to_string(), entry.clone());
@@ -223,22 +562,188 @@ impl InMemoryCache {
         let entry = CacheEntry {
             value: CachedIssue {
                 markdown: fresh_markdown.clone(),
+                last_access: self.next_access_seq(),
+                content_hash: fresh_hash,
             },
             cached_at: now,
             ttl: self.issue_ttl,
             source_updated: fresh_updated.clone(),
         };
-        self.issue_markdown
-            .lock_or_recover("issue_markdown")
-            .insert(issue_key.to_string(), entry);
+        self.insert_issue_markdown(issue_key, entry);
 
         if let Some(persistent) = &self.persistent {
             let _ = persistent.upsert_issue(issue_key, &fresh_markdown, fresh_updated.as_deref());
         }
 
+        self.broadcast(CacheEvent {
+            issue_key: issue_key.to_string(),
+            kind: CacheEventKind::Refreshed,
+            source_updated: fresh_updated,
+        });
+
         Ok(fresh_markdown)
     }
 
+    /// Runs `fetch` for `issue_key` with single-flight coalescing: the first
+    /// concurrent caller for a key (the "leader") runs `fetch` and publishes
+    /// the result into a shared slot; every other concurrent caller for the
+    /// same key (a "follower") blocks on that slot instead of also hitting
+    /// Jira. The slot is removed as soon as it's populated, via an RAII
+    /// guard, so a leader that panics mid-fetch still frees the key for the
+    /// next caller instead of wedging it forever — followers left waiting in
+    /// that case retry as a fresh leader rather than stalling.
+    fn fetch_single_flight<F, E>(&self, issue_key: &str, fetch: F) -> Result<(Vec<u8>, Option<String>), E>
+    where
+        F: FnOnce() -> Result<(Vec<u8>, Option<String>), E>,
+        E: Clone + Send + Sync + 'static,
+    {
+        let (slot, is_leader) = {
+            let mut inflight = self.inflight_fetches.lock_or_recover("inflight_fetches");
+            match inflight.get(issue_key) {
+                Some(existing) => (
+                    Arc::clone(existing)
+                        .downcast::<InflightSlot<E>>()
+                        .ok()
+                        .expect("inflight slot type mismatch for issue key"),
+                    false,
+                ),
+                None => {
+                    let slot: Arc<InflightSlot<E>> = Arc::new((Mutex::new(None), Condvar::new()));
+                    inflight.insert(issue_key.to_string(), Arc::clone(&slot) as Arc<dyn Any + Send + Sync>);
+                    (slot, true)
+                }
+            }
+        };
+        let (result_slot, condvar) = &*slot;
+
+        if !is_leader {
+            let mut guard = result_slot.lock_or_recover("inflight result");
+            loop {
+                if guard.is_some() {
+                    break;
+                }
+                guard = condvar.wait(guard).expect("inflight condvar mutex poisoned");
+                if guard.is_some() {
+                    break;
+                }
+                if !self
+                    .inflight_fetches
+                    .lock_or_recover("inflight_fetches")
+                    .contains_key(issue_key)
+                {
+                    // The leader finished without publishing a result (it
+                    // panicked); stop waiting on this slot.
+                    break;
+                }
+            }
+            if let Some(result) = guard.clone() {
+                self.metrics.inc_cache_miss();
+                return result;
+            }
+            drop(guard);
+            return self.fetch_single_flight(issue_key, fetch);
+        }
+
+        self.metrics.inc_cache_miss();
+        let _remove_on_exit = InflightGuard {
+            cache: self,
+            issue_key: issue_key.to_string(),
+            slot: Arc::clone(&slot),
+        };
+        let fetched = fetch();
+        *result_slot.lock_or_recover("inflight result") = Some(fetched.clone());
+        fetched
+    }
+
+    fn next_access_seq(&self) -> u64 {
+        self.access_seq.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Bumps an `issue_markdown` entry's recency without changing its size,
+    /// so a hot entry survives eviction even if it hasn't been refetched.
+    fn touch_issue_access(&self, issue_key: &str) {
+        let seq = self.next_access_seq();
+        if let Some(entry) = self
+            .issue_markdown
+            .lock_or_recover("issue_markdown")
+            .get_mut(issue_key)
+        {
+            entry.value.last_access = seq;
+        }
+    }
+
+    /// Inserts (or replaces) an `issue_markdown` entry, keeping `resident_bytes`
+    /// in sync with the map's actual content, then evicts the coldest entries
+    /// if that pushed the cache over `max_in_memory_bytes`.
+    fn insert_issue_markdown(&self, issue_key: &str, entry: CacheEntry<CachedIssue>) {
+        let new_len = entry.value.markdown.len() as u64;
+        let old_len = {
+            let mut guard = self.issue_markdown.lock_or_recover("issue_markdown");
+            let old_len = guard
+                .get(issue_key)
+                .map(|e| e.value.markdown.len() as u64)
+                .unwrap_or(0);
+            guard.insert(issue_key.to_string(), entry);
+            old_len
+        };
+
+        if new_len >= old_len {
+            self.resident_bytes
+                .fetch_add(new_len - old_len, Ordering::Relaxed);
+        } else {
+            self.release_resident_bytes(old_len - new_len);
+        }
+        self.metrics
+            .set_resident_issue_bytes(self.resident_bytes.load(Ordering::Relaxed));
+
+        self.evict_if_over_budget();
+    }
+
+    fn release_resident_bytes(&self, freed: u64) {
+        self.resident_bytes.fetch_sub(freed, Ordering::Relaxed);
+        self.metrics
+            .set_resident_issue_bytes(self.resident_bytes.load(Ordering::Relaxed));
+    }
+
+    /// Evicts the least-recently-accessed `issue_markdown` entries until
+    /// `resident_bytes` is back under `max_in_memory_bytes`. Lossless
+    /// whenever `persistent` is configured: `get_issue_markdown_stale_safe`
+    /// re-hydrates an evicted-but-persisted issue straight from persistence on
+    /// its next read.
+    fn evict_if_over_budget(&self) {
+        let Some(budget) = self.max_in_memory_bytes else {
+            return;
+        };
+
+        loop {
+            if self.resident_bytes.load(Ordering::Relaxed) <= budget {
+                return;
+            }
+
+            let mut guard = self.issue_markdown.lock_or_recover("issue_markdown");
+            let Some(coldest_key) = guard
+                .iter()
+                .min_by_key(|(_, entry)| entry.value.last_access)
+                .map(|(key, _)| key.clone())
+            else {
+                return;
+            };
+            let Some(removed) = guard.remove(&coldest_key) else {
+                return;
+            };
+            drop(guard);
+
+            let freed = removed.value.markdown.len() as u64;
+            self.release_resident_bytes(freed);
+            self.metrics.inc_issue_cache_eviction();
+            self.broadcast(CacheEvent {
+                issue_key: coldest_key,
+                kind: CacheEventKind::Evicted,
+                source_updated: removed.source_updated,
+            });
+        }
+    }
+
     /// Returns in-memory markdown length in bytes for one issue.
     pub fn cached_issue_len(&self, issue_key: &str) -> Option<u64> {
         self.issue_markdown
@@ -253,18 +758,24 @@ impl InMemoryCache {
         let entry = CacheEntry {
             value: CachedIssue {
                 markdown: markdown.to_vec(),
+                last_access: self.next_access_seq(),
+                content_hash: backend::content_hash(markdown),
             },
             cached_at: now,
             ttl: self.issue_ttl,
             source_updated: updated.map(ToString::to_string),
         };
-        self.issue_markdown
-            .lock_or_recover("issue_markdown")
-            .insert(issue_key.to_string(), entry);
+        self.insert_issue_markdown(issue_key, entry);
 
         if let Some(persistent) = &self.persistent {
             let _ = persistent.upsert_issue(issue_key, markdown, updated);
         }
+
+        self.broadcast(CacheEvent {
+            issue_key: issue_key.to_string(),
+            kind: CacheEventKind::Refreshed,
+            source_updated: updated.map(ToString::to_string),
+        });
     }
 
     /// Upserts a batch of issue payloads into memory and persistence.
@@ -272,20 +783,24 @@ impl InMemoryCache {
         let now = Instant::now();
         let mut count = 0;
 
-        {
-            let mut guard = self.issue_markdown.lock_or_recover("issue_markdown");
-            for (issue_key, markdown, updated) in issues {
-                let entry = CacheEntry {
-                    value: CachedIssue {
-                        markdown: markdown.clone(),
-                    },
-                    cached_at: now,
-                    ttl: self.issue_ttl,
-                    source_updated: updated.clone(),
-                };
-                guard.insert(issue_key.clone(), entry);
-                count += 1;
-            }
+        for (issue_key, markdown, updated) in issues {
+            let entry = CacheEntry {
+                value: CachedIssue {
+                    markdown: markdown.clone(),
+                    last_access: self.next_access_seq(),
+                    content_hash: backend::content_hash(markdown),
+                },
+                cached_at: now,
+                ttl: self.issue_ttl,
+                source_updated: updated.clone(),
+            };
+            self.insert_issue_markdown(issue_key, entry);
+            self.broadcast(CacheEvent {
+                issue_key: issue_key.clone(),
+                kind: CacheEventKind::Refreshed,
+                source_updated: updated.clone(),
+            });
+            count += 1;
         }
 
         if let Some(persistent) = &self.persistent {
@@ -305,6 +820,80 @@ impl InMemoryCache {
         0
     }
 
+    /// Returns the currently cached markdown for one issue, if any, checked
+    /// in memory first and falling back to persistence. Used to snapshot the
+    /// outgoing bytes into history before they're overwritten by a refresh.
+    pub fn current_issue_markdown(&self, issue_key: &str) -> Option<Vec<u8>> {
+        if let Some(entry) = self
+            .issue_markdown
+            .lock_or_recover("issue_markdown")
+            .get(issue_key)
+        {
+            return Some(entry.value.markdown.clone());
+        }
+
+        self.persistent
+            .as_ref()
+            .and_then(|persistent| persistent.get_issue(issue_key).ok().flatten())
+            .map(|issue| issue.markdown)
+    }
+
+    /// Returns Jira's raw `updated` timestamp string for an issue, checked in
+    /// memory first and falling back to persistence. Used to derive a real
+    /// file `mtime` instead of the `UNIX_EPOCH` placeholder; see
+    /// `fs::JiraFuseFs::issue_file_attr`.
+    pub fn source_updated_for_issue(&self, issue_key: &str) -> Option<String> {
+        if let Some(entry) = self
+            .issue_markdown
+            .lock_or_recover("issue_markdown")
+            .get(issue_key)
+        {
+            return entry.source_updated.clone();
+        }
+
+        self.persistent
+            .as_ref()
+            .and_then(|persistent| persistent.get_issue(issue_key).ok().flatten())
+            .and_then(|issue| issue.updated)
+    }
+
+    /// Retains `markdown` as a new version in an issue's `.history/` ring,
+    /// trimmed back to [`DEFAULT_HISTORY_MAX_VERSIONS`]. No-op without
+    /// persistence, since the ring must survive remounts to be useful.
+    pub fn record_issue_history(&self, issue_key: &str, markdown: &[u8]) {
+        let Some(persistent) = &self.persistent else {
+            return;
+        };
+        if let Err(err) =
+            persistent.append_issue_history(issue_key, markdown, DEFAULT_HISTORY_MAX_VERSIONS)
+        {
+            logging::warn(format!(
+                "failed to record history for {}: {}",
+                issue_key, err
+            ));
+        }
+    }
+
+    /// Lists retained `.history/` version ids for an issue, oldest first.
+    pub fn list_issue_history_versions(&self, issue_key: &str) -> Vec<String> {
+        self.persistent
+            .as_ref()
+            .and_then(|persistent| persistent.list_issue_history_versions(issue_key).ok())
+            .unwrap_or_default()
+    }
+
+    /// Reads one retained `.history/` snapshot's markdown bytes.
+    pub fn get_issue_history_version(&self, issue_key: &str, version_id: &str) -> Option<Vec<u8>> {
+        self.persistent
+            .as_ref()
+            .and_then(|persistent| {
+                persistent
+                    .get_issue_history_version(issue_key, version_id)
+                    .ok()
+            })
+            .flatten()
+    }
+
     /// Returns persisted sync cursor for a project when available.
     pub fn get_sync_cursor(&self, project: &str) -> Option<String> {
         self.persistent
@@ -360,6 +949,38 @@ impl InMemoryCache {
             .and_then(|p| p.list_project_issue_refs(project).ok())
     }
 
+    /// Appends a freshly-posted comment onto an issue's persisted sidecars,
+    /// so a subsequent read reflects it without waiting for the next sync.
+    pub fn append_issue_comment(&self, issue_key: &str, comment_markdown: &str, comment_jsonl_line: &str) {
+        let Some(persistent) = &self.persistent else {
+            return;
+        };
+
+        let mut md = persistent
+            .get_issue_comments_md(issue_key)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        if !md.is_empty() && !md.ends_with(b"\n") {
+            md.push(b'\n');
+        }
+        md.extend_from_slice(comment_markdown.as_bytes());
+
+        let mut jsonl = persistent
+            .get_issue_comments_jsonl(issue_key)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        jsonl.extend_from_slice(comment_jsonl_line.as_bytes());
+
+        if let Err(err) = persistent.upsert_issue_sidecars(issue_key, &md, &jsonl, None) {
+            logging::warn(format!(
+                "failed to append comment sidecar for {}: {}",
+                issue_key, err
+            ));
+        }
+    }
+
     /// Returns persisted comments markdown sidecar bytes.
     pub fn persistent_comments_md(&self, issue_key: &str) -> Option<Vec<u8>> {
         self.persistent
@@ -387,6 +1008,280 @@ impl InMemoryCache {
             .as_ref()
             .and_then(|p| p.issue_comments_jsonl_len(issue_key).ok().flatten())
     }
+
+    /// Loads the in-flight sync checkpoint, if one was persisted.
+    pub fn get_sync_checkpoint(&self) -> Option<SyncCheckpoint> {
+        let blob = self
+            .persistent
+            .as_ref()
+            .and_then(|p| p.get_blob(SYNC_CHECKPOINT_BLOB_KEY).ok().flatten())?;
+        match rmp_serde::from_slice(&blob) {
+            Ok(checkpoint) => Some(checkpoint),
+            Err(err) => {
+                logging::warn(format!("discarding unreadable sync checkpoint: {}", err));
+                None
+            }
+        }
+    }
+
+    /// Persists a checkpoint so a killed sync can resume from it on remount.
+    pub fn set_sync_checkpoint(&self, checkpoint: &SyncCheckpoint) {
+        let Some(persistent) = &self.persistent else {
+            return;
+        };
+        match rmp_serde::to_vec(checkpoint) {
+            Ok(bytes) => {
+                let _ = persistent.set_blob(SYNC_CHECKPOINT_BLOB_KEY, &bytes);
+            }
+            Err(err) => logging::warn(format!("failed to encode sync checkpoint: {}", err)),
+        }
+    }
+
+    /// Clears the in-flight sync checkpoint after a clean completion.
+    pub fn clear_sync_checkpoint(&self) {
+        if let Some(persistent) = &self.persistent {
+            let _ = persistent.clear_blob(SYNC_CHECKPOINT_BLOB_KEY);
+        }
+    }
+
+    /// Overrides the bounded ring size for historical sync tasks (default
+    /// [`DEFAULT_SYNC_TASK_RETENTION`]); trims and re-persists the ring
+    /// immediately if it's already past the new bound.
+    pub fn set_sync_task_retention(&self, retention: usize) {
+        self.sync_task_retention.store(retention.max(1), Ordering::Relaxed);
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.trim_sync_tasks(&mut tasks);
+        self.persist_sync_tasks(&tasks);
+    }
+
+    /// Records that a new `sync_issues`/`seed_workspace_listings` pass has
+    /// begun, returning its id. The task starts `Running`; callers follow up
+    /// with [`Self::finish_sync_task`] once the pass completes.
+    pub fn enqueue_sync_task(&self, kind: SyncTaskKind, workspaces: Vec<String>) -> u64 {
+        let now = unix_epoch_seconds();
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.hydrate_sync_tasks_if_needed(&mut tasks);
+        let id = self.next_sync_task_id.fetch_add(1, Ordering::Relaxed);
+        tasks.push_back(SyncTask {
+            id,
+            workspaces,
+            kind,
+            status: SyncTaskStatus::Running,
+            enqueued_at: now,
+            started_at: None,
+            finished_at: None,
+            errors: Vec::new(),
+        });
+        self.trim_sync_tasks(&mut tasks);
+        self.persist_sync_tasks(&tasks);
+        id
+    }
+
+    /// Records that a previously enqueued task has begun doing work. A no-op
+    /// if `id` isn't (or is no longer) present.
+    pub fn start_sync_task(&self, id: u64) {
+        let now = unix_epoch_seconds();
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.hydrate_sync_tasks_if_needed(&mut tasks);
+        if let Some(task) = tasks.iter_mut().find(|task| task.id == id) {
+            task.started_at = Some(now);
+        }
+        self.persist_sync_tasks(&tasks);
+    }
+
+    /// Marks a previously enqueued task terminal with `status` and any
+    /// `errors` it collected. A no-op if `id` isn't (or is no longer, having
+    /// aged out of the ring) present.
+    pub fn finish_sync_task(&self, id: u64, status: SyncTaskStatus, errors: Vec<String>) {
+        let now = unix_epoch_seconds();
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.hydrate_sync_tasks_if_needed(&mut tasks);
+        if let Some(task) = tasks.iter_mut().find(|task| task.id == id) {
+            task.finished_at = Some(now);
+            task.status = status;
+            task.errors = errors;
+        }
+        self.persist_sync_tasks(&tasks);
+    }
+
+    /// Lists up to `limit` most recent sync tasks, newest first.
+    pub fn list_recent_sync_tasks(&self, limit: usize) -> Vec<SyncTask> {
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.hydrate_sync_tasks_if_needed(&mut tasks);
+        tasks.iter().rev().take(limit).cloned().collect()
+    }
+
+    /// Fetches a single task by id, regardless of how recent it is within
+    /// the ring.
+    pub fn get_sync_task(&self, id: u64) -> Option<SyncTask> {
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.hydrate_sync_tasks_if_needed(&mut tasks);
+        tasks.iter().find(|task| task.id == id).cloned()
+    }
+
+    /// Returns the most recently enqueued task still `Running`, if any, so
+    /// callers (e.g. a `.sync-status` virtual file) can report live progress.
+    pub fn current_running_sync_task(&self) -> Option<SyncTask> {
+        let mut tasks = self.sync_tasks.lock_or_recover("sync_tasks");
+        self.hydrate_sync_tasks_if_needed(&mut tasks);
+        tasks
+            .iter()
+            .rev()
+            .find(|task| task.status == SyncTaskStatus::Running)
+            .cloned()
+    }
+
+    /// Drops the oldest tasks until the ring is back within
+    /// `sync_task_retention`.
+    fn trim_sync_tasks(&self, tasks: &mut VecDeque<SyncTask>) {
+        let retention = self.sync_task_retention.load(Ordering::Relaxed);
+        while tasks.len() > retention {
+            tasks.pop_front();
+        }
+    }
+
+    /// Mirrors the whole task ring to `SYNC_TASKS_BLOB_KEY`, best-effort, so
+    /// it survives a restart.
+    fn persist_sync_tasks(&self, tasks: &VecDeque<SyncTask>) {
+        let Some(persistent) = &self.persistent else {
+            return;
+        };
+        let ordered: Vec<&SyncTask> = tasks.iter().collect();
+        match rmp_serde::to_vec(&ordered) {
+            Ok(bytes) => {
+                let _ = persistent.set_blob(SYNC_TASKS_BLOB_KEY, &bytes);
+            }
+            Err(err) => logging::warn(format!("failed to encode sync task history: {}", err)),
+        }
+    }
+
+    /// Restores the task ring from `SYNC_TASKS_BLOB_KEY` the first time a
+    /// sync-task method runs in a fresh process, so history survives a
+    /// restart; a no-op on every later call.
+    fn hydrate_sync_tasks_if_needed(&self, tasks: &mut VecDeque<SyncTask>) {
+        if self.sync_tasks_hydrated.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let Some(persistent) = &self.persistent else {
+            return;
+        };
+        match persistent.get_blob(SYNC_TASKS_BLOB_KEY) {
+            Ok(Some(blob)) => match rmp_serde::from_slice::<Vec<SyncTask>>(&blob) {
+                Ok(restored) => *tasks = restored.into(),
+                Err(err) => {
+                    logging::warn(format!("discarding unreadable sync task history: {}", err))
+                }
+            },
+            Ok(None) => {}
+            Err(err) => logging::warn(format!("failed to load sync task history: {}", err)),
+        }
+    }
+
+    /// Loads the digest a reconciliation pass last stored for `project`, so
+    /// it can skip the expensive full diff when nothing has changed; see
+    /// `warmup::reconcile_projects`.
+    pub fn get_reconcile_digest(&self, project: &str) -> Option<String> {
+        let blob = self
+            .persistent
+            .as_ref()
+            .and_then(|p| p.get_blob(&reconcile_digest_blob_key(project)).ok().flatten())?;
+        String::from_utf8(blob).ok()
+    }
+
+    /// Persists the digest a reconciliation pass computed for `project`.
+    pub fn set_reconcile_digest(&self, project: &str, digest: &str) {
+        if let Some(persistent) = &self.persistent {
+            let _ = persistent.set_blob(&reconcile_digest_blob_key(project), digest.as_bytes());
+        }
+    }
+
+    /// Lists all saved virtual query directories, as (name, jql) pairs.
+    pub fn list_queries(&self) -> Vec<(String, String)> {
+        let Some(persistent) = &self.persistent else {
+            return Vec::new();
+        };
+        match persistent.list_queries() {
+            Ok(queries) => queries,
+            Err(err) => {
+                logging::warn(format!("failed to list saved queries: {}", err));
+                Vec::new()
+            }
+        }
+    }
+
+    /// Saves a virtual query directory so it survives a remount.
+    pub fn upsert_query(&self, name: &str, jql: &str) {
+        if let Some(persistent) = &self.persistent {
+            if let Err(err) = persistent.upsert_query(name, jql) {
+                logging::warn(format!("failed to persist query {}: {}", name, err));
+            }
+        }
+    }
+
+    /// Drops a saved virtual query directory.
+    pub fn remove_query(&self, name: &str) {
+        if let Some(persistent) = &self.persistent {
+            if let Err(err) = persistent.remove_query(name) {
+                logging::warn(format!("failed to remove query {}: {}", name, err));
+            }
+        }
+
+        self.query_issues.lock_or_recover("query_issues").remove(name);
+    }
+
+    /// Returns a saved query's matching issues, with staleness signal.
+    pub fn get_query_issues_snapshot(&self, name: &str) -> Option<ProjectIssuesSnapshot> {
+        let now = Instant::now();
+        let entry = self
+            .query_issues
+            .lock_or_recover("query_issues")
+            .get(name)
+            .cloned()?;
+
+        let is_stale = now.duration_since(entry.cached_at) >= entry.ttl;
+        if is_stale {
+            self.metrics.inc_cache_miss();
+        } else {
+            self.metrics.inc_cache_hit();
+        }
+
+        Some(ProjectIssuesSnapshot {
+            issues: entry.value,
+            is_stale,
+        })
+    }
+
+    /// Replaces the cached issue matches for a saved query.
+    pub fn upsert_query_issues(&self, name: &str, issues: Vec<IssueRef>) {
+        let entry = CacheEntry {
+            value: issues,
+            cached_at: Instant::now(),
+            ttl: self.project_ttl,
+            source_updated: None,
+        };
+        self.query_issues
+            .lock_or_recover("query_issues")
+            .insert(name.to_string(), entry);
+    }
+}
+
+/// Removes `issue_key`'s single-flight slot from the cache and wakes any
+/// followers waiting on it, whether this guard drops normally (result
+/// published) or mid-unwind (the leader's `fetch` panicked).
+struct InflightGuard<'a, E: Send + Sync + 'static> {
+    cache: &'a InMemoryCache,
+    issue_key: String,
+    slot: Arc<InflightSlot<E>>,
+}
+
+impl<E: Send + Sync + 'static> Drop for InflightGuard<'_, E> {
+    fn drop(&mut self) {
+        self.cache
+            .inflight_fetches
+            .lock_or_recover("inflight_fetches")
+            .remove(&self.issue_key);
+        self.slot.1.notify_all();
+    }
 }
 
 trait MutexExt<T> {
@@ -419,7 +1314,7 @@ mod tests {
 
     #[test]
     fn issue_cache_hits_within_ttl() {
-        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), metrics());
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
         let calls = Arc::new(AtomicUsize::new(0));
 
         let c1 = Arc::clone(&calls);
@@ -443,9 +1338,74 @@ mod tests {
         assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
 
+    #[test]
+    fn unchanged_content_hash_skips_replacement_even_when_updated_changes() {
+        // TTL 0 forces every call past the fresh-hit branch and into a real
+        // refresh, so we can exercise the post-fetch hash comparison.
+        let cache = InMemoryCache::new(Duration::from_secs(0), Duration::from_secs(0), None, metrics());
+
+        cache
+            .get_issue_markdown_stale_safe("PROJ-1", || {
+                Ok::<_, String>((b"same content".to_vec(), Some("u1".to_string())))
+            })
+            .expect("seed");
+
+        let refreshed = cache
+            .get_issue_markdown_stale_safe("PROJ-1", || {
+                // Jira's `updated` changed, but the rendered markdown didn't.
+                Ok::<_, String>((b"same content".to_vec(), Some("u2".to_string())))
+            })
+            .expect("refresh");
+        assert_eq!(refreshed, b"same content");
+
+        // The in-memory replacement was skipped: `source_updated` still
+        // carries the original value, not the fetch's "u2".
+        let entry = cache
+            .issue_markdown
+            .lock_or_recover("issue_markdown")
+            .get("PROJ-1")
+            .cloned()
+            .expect("entry present");
+        assert_eq!(entry.source_updated.as_deref(), Some("u1"));
+    }
+
+    #[test]
+    fn subscribers_see_refresh_and_eviction_events() {
+        let cache = InMemoryCache::new(Duration::from_secs(0), Duration::from_secs(0), Some(6), metrics());
+        let rx = cache.subscribe();
+
+        cache.upsert_issue_direct("PROJ-1", b"hello", Some("u1"));
+        let refreshed = rx.try_recv().expect("refreshed event");
+        assert_eq!(refreshed.issue_key, "PROJ-1");
+        assert_eq!(refreshed.kind, CacheEventKind::Refreshed);
+        assert_eq!(refreshed.source_updated.as_deref(), Some("u1"));
+
+        // Budget of 6 bytes: this second insert evicts PROJ-1, which
+        // broadcasts before the new entry's own Refreshed event.
+        cache.upsert_issue_direct("PROJ-2", b"hi!!", Some("u2"));
+
+        let evicted = rx.try_recv().expect("evicted event");
+        assert_eq!(evicted.issue_key, "PROJ-1");
+        assert_eq!(evicted.kind, CacheEventKind::Evicted);
+        assert_eq!(evicted.source_updated.as_deref(), Some("u1"));
+
+        let refreshed2 = rx.try_recv().expect("second refreshed event");
+        assert_eq!(refreshed2.issue_key, "PROJ-2");
+        assert_eq!(refreshed2.kind, CacheEventKind::Refreshed);
+    }
+
+    #[test]
+    fn dropped_receiver_does_not_block_broadcast() {
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
+        drop(cache.subscribe());
+
+        cache.upsert_issue_direct("PROJ-1", b"hello", Some("u1"));
+        assert!(cache.subscribers.lock_or_recover("subscribers").is_empty());
+    }
+
     #[test]
     fn stale_is_served_when_refresh_fails() {
-        let cache = InMemoryCache::new(Duration::from_secs(0), Duration::from_secs(0), metrics());
+        let cache = InMemoryCache::new(Duration::from_secs(0), Duration::from_secs(0), None, metrics());
         let first = cache
             .get_issue_markdown_stale_safe("PROJ-1", || {
                 Ok::<_, String>((b"old".to_vec(), Some("same".to_string())))
@@ -467,7 +1427,13 @@ mod tests {
         let cache = InMemoryCache::with_persistence(
             Duration::from_secs(60),
             Duration::from_secs(60),
+            None,
+            PersistenceBackendKind::Sqlite,
             Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
             metrics(),
         )
         .expect("cache");
@@ -485,4 +1451,319 @@ mod tests {
             .expect("loaded from cache");
         assert_eq!(got, b"persisted");
     }
+
+    #[test]
+    fn evicts_coldest_issue_when_over_byte_budget_but_stays_readable_via_persistence() {
+        let cache = InMemoryCache::with_persistence(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            Some(12),
+            PersistenceBackendKind::Sqlite,
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("cache");
+
+        cache.upsert_issue_direct("PROJ-1", b"0123456789", Some("u1"));
+        cache.upsert_issue_direct("PROJ-2", b"0123456789", Some("u2"));
+
+        // Over budget now (20 bytes resident vs a 12 byte cap): PROJ-1 is
+        // the colder entry (inserted first, never touched again) and should
+        // have been evicted from memory to make room for PROJ-2.
+        assert!(cache.cached_issue_len("PROJ-1").is_none());
+        assert_eq!(cache.cached_issue_len("PROJ-2"), Some(10));
+
+        // Eviction never loses data while persistence is configured: a read
+        // transparently re-hydrates PROJ-1 from SQLite.
+        let got = cache
+            .get_issue_markdown_stale_safe("PROJ-1", || {
+                Err::<(Vec<u8>, Option<String>), _>("unused".to_string())
+            })
+            .expect("re-hydrated from persistence");
+        assert_eq!(got, b"0123456789");
+    }
+
+    #[test]
+    fn sync_checkpoint_roundtrip() {
+        let cache = InMemoryCache::with_persistence(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            None,
+            PersistenceBackendKind::Sqlite,
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("cache");
+
+        assert!(cache.get_sync_checkpoint().is_none());
+
+        let checkpoint = SyncCheckpoint {
+            project: "PROJ".to_string(),
+            start_at: 100,
+            remaining_budget: 50,
+            cached_this_run: vec!["PROJ-1".to_string(), "PROJ-2".to_string()],
+        };
+        cache.set_sync_checkpoint(&checkpoint);
+
+        let loaded = cache.get_sync_checkpoint().expect("present");
+        assert_eq!(loaded.project, "PROJ");
+        assert_eq!(loaded.start_at, 100);
+        assert_eq!(loaded.cached_this_run, checkpoint.cached_this_run);
+
+        cache.clear_sync_checkpoint();
+        assert!(cache.get_sync_checkpoint().is_none());
+    }
+
+    #[test]
+    fn saved_query_roundtrip() {
+        let cache = InMemoryCache::with_persistence(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            None,
+            PersistenceBackendKind::Sqlite,
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("cache");
+
+        assert!(cache.list_queries().is_empty());
+
+        cache.upsert_query("open%20bugs", "project = PROJ AND status != Done");
+        let queries = cache.list_queries();
+        assert_eq!(queries, vec![(
+            "open%20bugs".to_string(),
+            "project = PROJ AND status != Done".to_string(),
+        )]);
+
+        cache.remove_query("open%20bugs");
+        assert!(cache.list_queries().is_empty());
+    }
+
+    #[test]
+    fn appends_comment_onto_existing_sidecars() {
+        let cache = InMemoryCache::with_persistence(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            None,
+            PersistenceBackendKind::Sqlite,
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("cache");
+
+        cache.append_issue_comment("PROJ-1", "**alice**: first\n", "{\"body\":\"first\"}\n");
+        cache.append_issue_comment("PROJ-1", "**bob**: second\n", "{\"body\":\"second\"}\n");
+
+        let md = String::from_utf8(cache.persistent_comments_md("PROJ-1").expect("md")).unwrap();
+        assert!(md.contains("first"));
+        assert!(md.contains("second"));
+
+        let jsonl =
+            String::from_utf8(cache.persistent_comments_jsonl("PROJ-1").expect("jsonl")).unwrap();
+        assert_eq!(jsonl.lines().count(), 2);
+    }
+
+    #[test]
+    fn query_issues_cached_until_removed() {
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
+
+        assert!(cache.get_query_issues_snapshot("open_bugs").is_none());
+
+        cache.upsert_query_issues(
+            "open_bugs",
+            vec![IssueRef {
+                key: "PROJ-1".to_string(),
+                updated: Some("2024-01-01T00:00:00Z".to_string()),
+            }],
+        );
+
+        let snapshot = cache.get_query_issues_snapshot("open_bugs").expect("cached");
+        assert!(!snapshot.is_stale);
+        assert_eq!(snapshot.issues.len(), 1);
+
+        cache.remove_query("open_bugs");
+        assert!(cache.get_query_issues_snapshot("open_bugs").is_none());
+    }
+
+    #[test]
+    fn issue_history_records_prior_markdown_before_overwrite() {
+        let cache = InMemoryCache::with_persistence(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            None,
+            PersistenceBackendKind::Sqlite,
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("cache");
+
+        cache.upsert_issue_direct("PROJ-1", b"v1", Some("u1"));
+        assert!(cache.list_issue_history_versions("PROJ-1").is_empty());
+
+        let previous = cache.current_issue_markdown("PROJ-1").expect("v1 present");
+        cache.record_issue_history("PROJ-1", &previous);
+        cache.upsert_issue_direct("PROJ-1", b"v2", Some("u2"));
+
+        let versions = cache.list_issue_history_versions("PROJ-1");
+        assert_eq!(versions.len(), 1);
+        let snapshot = cache
+            .get_issue_history_version("PROJ-1", &versions[0])
+            .expect("version present");
+        assert_eq!(snapshot, b"v1");
+        assert_eq!(cache.current_issue_markdown("PROJ-1").unwrap(), b"v2");
+    }
+
+    #[test]
+    fn no_persistence_means_no_history() {
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
+        cache.upsert_issue_direct("PROJ-1", b"v1", Some("u1"));
+        cache.record_issue_history("PROJ-1", b"v1");
+        assert!(cache.list_issue_history_versions("PROJ-1").is_empty());
+    }
+
+    #[test]
+    fn concurrent_misses_coalesce_into_a_single_fetch() {
+        let cache = Arc::new(InMemoryCache::new(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            None,
+            metrics(),
+        ));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(std::sync::Barrier::new(8));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                let calls = Arc::clone(&calls);
+                let release = Arc::clone(&release);
+                std::thread::spawn(move || {
+                    release.wait();
+                    cache
+                        .get_issue_markdown_stale_safe("PROJ-1", || {
+                            calls.fetch_add(1, Ordering::SeqCst);
+                            std::thread::sleep(Duration::from_millis(20));
+                            Ok::<_, String>((b"shared".to_vec(), Some("u1".to_string())))
+                        })
+                        .expect("fetch")
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().expect("thread"), b"shared");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn remove_issue_everywhere_drops_it_from_project_listing_and_markdown() {
+        let cache = InMemoryCache::with_persistence(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            None,
+            PersistenceBackendKind::Sqlite,
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("cache");
+
+        cache.upsert_project_issues(
+            "PROJ",
+            vec![
+                crate::jira::IssueRef {
+                    key: "PROJ-1".to_string(),
+                    updated: Some("u1".to_string()),
+                },
+                crate::jira::IssueRef {
+                    key: "PROJ-2".to_string(),
+                    updated: Some("u2".to_string()),
+                },
+            ],
+        );
+        cache.upsert_issue_direct("PROJ-1", b"v1", Some("u1"));
+
+        cache.remove_issue_everywhere("PROJ", "PROJ-1");
+
+        let remaining = cache
+            .get_project_issues_snapshot("PROJ")
+            .expect("snapshot")
+            .issues;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].key, "PROJ-2");
+        assert!(cache.current_issue_markdown("PROJ-1").is_none());
+    }
+
+    #[test]
+    fn gc_tombstones_forgets_only_expired_entries() {
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
+
+        cache.tombstone_issue("PROJ", "PROJ-1", 1_000);
+        cache.tombstone_issue("PROJ", "PROJ-2", 1_900);
+
+        let forgotten = cache.gc_tombstones("PROJ", 500, 2_000);
+        assert_eq!(forgotten, 1);
+
+        let remaining = cache.list_tombstones("PROJ");
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].issue_key, "PROJ-2");
+    }
+
+    #[test]
+    fn sync_task_lifecycle_tracks_status_and_errors() {
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
+
+        let id = cache.enqueue_sync_task(SyncTaskKind::Incremental, vec!["PROJ".to_string()]);
+        assert_eq!(cache.current_running_sync_task().expect("running").id, id);
+
+        cache.start_sync_task(id);
+        let running = cache.get_sync_task(id).expect("task present");
+        assert!(running.started_at.is_some());
+        assert_eq!(running.status, SyncTaskStatus::Running);
+
+        cache.finish_sync_task(id, SyncTaskStatus::Partial, vec!["boom".to_string()]);
+        let finished = cache.get_sync_task(id).expect("task present");
+        assert_eq!(finished.status, SyncTaskStatus::Partial);
+        assert_eq!(finished.errors, vec!["boom".to_string()]);
+        assert!(finished.finished_at.is_some());
+        assert!(cache.current_running_sync_task().is_none());
+    }
+
+    #[test]
+    fn sync_task_retention_bounds_the_history_ring() {
+        let cache = InMemoryCache::new(Duration::from_secs(60), Duration::from_secs(60), None, metrics());
+        cache.set_sync_task_retention(2);
+
+        let first = cache.enqueue_sync_task(SyncTaskKind::Full, vec!["A".to_string()]);
+        cache.enqueue_sync_task(SyncTaskKind::Full, vec!["B".to_string()]);
+        cache.enqueue_sync_task(SyncTaskKind::Full, vec!["C".to_string()]);
+
+        let recent = cache.list_recent_sync_tasks(10);
+        assert_eq!(recent.len(), 2, "ring should be trimmed to the configured retention");
+        assert!(cache.get_sync_task(first).is_none(), "oldest task should have aged out");
+    }
 }
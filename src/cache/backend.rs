@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use crate::jira::IssueRef;
+use crate::metrics::Metrics;
+
+use super::persistent::{PersistentIssue, PersistentIssueRow, PersistentSidecarRow, TicketIndexRow};
+
+/// Error type returned by [`PersistenceBackend`] methods, erased so callers
+/// don't need to know which concrete storage engine is behind the trait
+/// object (`rusqlite::Error` for SQLite, `heed::Error` for LMDB).
+pub type PersistenceError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Per-row codec tag for compressed payloads, so rows written before
+/// compression existed (tag absent/`0`) stay readable and the codec can
+/// evolve without a data migration.
+pub(crate) const CODEC_RAW: u8 = 0;
+pub(crate) const CODEC_ZSTD: u8 = 1;
+
+/// Default zstd level applied to persisted issue markdown and comment
+/// sidecars: fast, not maximal, since this runs on every sync write.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+
+/// Default minimum idle connections in the SQLite backend's read pool; see
+/// [`super::persistent::PersistentCache::new`]. Unused by the LMDB backend,
+/// which has no analogous single-writer bottleneck to pool around.
+pub const DEFAULT_MIN_READ_CONN: u32 = 1;
+
+/// Default maximum size of the SQLite backend's read pool; see
+/// [`super::persistent::PersistentCache::new`].
+pub const DEFAULT_MAX_READ_CONN: u32 = 4;
+
+/// Compresses `data` at `level`, reporting the ratio achieved to `metrics`.
+pub(crate) fn compress(level: i32, data: &[u8], metrics: &Metrics) -> Result<(u8, Vec<u8>), PersistenceError> {
+    let compressed = zstd::stream::encode_all(data, level)?;
+    metrics.observe_compression_ratio(data.len() as u64, compressed.len() as u64);
+    Ok((CODEC_ZSTD, compressed))
+}
+
+/// Decompresses `data` according to `codec`, passing it through unchanged
+/// for `CODEC_RAW` (pre-compression rows).
+pub(crate) fn decompress(codec: u8, data: &[u8]) -> Result<Vec<u8>, PersistenceError> {
+    match codec {
+        CODEC_ZSTD => Ok(zstd::stream::decode_all(data)?),
+        _ => Ok(data.to_vec()),
+    }
+}
+
+/// Content hash for change detection: hex-encoded BLAKE3 over the rendered
+/// markdown itself. Jira's `updated` timestamp can change without the
+/// rendered markdown actually changing (and is sometimes absent), so this is
+/// the authoritative "did the content change" signal, computed identically
+/// by the in-memory cache and both persistence backends.
+pub fn content_hash(markdown: &[u8]) -> String {
+    blake3::hash(markdown).to_hex().to_string()
+}
+
+/// Selects which concrete storage engine `InMemoryCache::with_persistence`
+/// opens. SQLite suits richer index queries (`list_ticket_index`,
+/// `cached_issue_count`'s `LIKE` scan); LMDB trades those off for lower
+/// write amplification on the hot issue-markdown upsert path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersistenceBackendKind {
+    Sqlite,
+    Lmdb,
+}
+
+/// The durable-storage surface `InMemoryCache` actually calls. Extracted so
+/// a deployment can pick the storage engine that fits its workload instead
+/// of being hard-wired to SQLite; see [`PersistenceBackendKind`].
+pub trait PersistenceBackend: std::fmt::Debug + Send + Sync {
+    fn get_issue(&self, issue_key: &str) -> Result<Option<PersistentIssue>, PersistenceError>;
+    fn upsert_issue(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        updated: Option<&str>,
+    ) -> Result<(), PersistenceError>;
+    fn remove_issue(&self, issue_key: &str) -> Result<(), PersistenceError>;
+    fn upsert_issues_batch(
+        &self,
+        issues: &[PersistentIssueRow],
+    ) -> Result<usize, PersistenceError>;
+
+    fn get_sync_cursor(&self, project: &str) -> Result<Option<String>, PersistenceError>;
+    fn set_sync_cursor(&self, project: &str, last_sync: &str) -> Result<(), PersistenceError>;
+    fn clear_sync_cursor(&self, project: &str) -> Result<(), PersistenceError>;
+
+    fn cached_issue_count(&self, project_prefix: &str) -> Result<usize, PersistenceError>;
+    fn issue_markdown_len(&self, issue_key: &str) -> Result<Option<u64>, PersistenceError>;
+    fn list_ticket_index(
+        &self,
+        projects: &[String],
+    ) -> Result<Vec<TicketIndexRow>, PersistenceError>;
+    fn list_project_issue_refs(&self, project: &str) -> Result<Vec<IssueRef>, PersistenceError>;
+    fn scan_ticket_index(
+        &self,
+        project: Option<&str>,
+        start_key: Option<&str>,
+        end_key: Option<&str>,
+        limit: usize,
+        reverse: bool,
+    ) -> Result<Vec<TicketIndexRow>, PersistenceError>;
+
+    fn upsert_issue_sidecars(
+        &self,
+        issue_key: &str,
+        comments_md: &[u8],
+        comments_jsonl: &[u8],
+        updated: Option<&str>,
+    ) -> Result<(), PersistenceError>;
+    fn upsert_issue_sidecars_batch(
+        &self,
+        sidecars: &[PersistentSidecarRow],
+    ) -> Result<usize, PersistenceError>;
+    fn get_issue_comments_md(&self, issue_key: &str) -> Result<Option<Vec<u8>>, PersistenceError>;
+    fn get_issue_comments_jsonl(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<Vec<u8>>, PersistenceError>;
+    fn issue_comments_md_len(&self, issue_key: &str) -> Result<Option<u64>, PersistenceError>;
+    fn issue_comments_jsonl_len(&self, issue_key: &str) -> Result<Option<u64>, PersistenceError>;
+
+    fn get_blob(&self, key: &str) -> Result<Option<Vec<u8>>, PersistenceError>;
+    fn set_blob(&self, key: &str, value: &[u8]) -> Result<(), PersistenceError>;
+    fn clear_blob(&self, key: &str) -> Result<(), PersistenceError>;
+
+    fn list_queries(&self) -> Result<Vec<(String, String)>, PersistenceError>;
+    fn upsert_query(&self, name: &str, jql: &str) -> Result<(), PersistenceError>;
+    fn remove_query(&self, name: &str) -> Result<(), PersistenceError>;
+
+    fn append_issue_history(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        max_versions: usize,
+    ) -> Result<String, PersistenceError>;
+    fn list_issue_history_versions(&self, issue_key: &str) -> Result<Vec<String>, PersistenceError>;
+    fn get_issue_history_version(
+        &self,
+        issue_key: &str,
+        version_id: &str,
+    ) -> Result<Option<Vec<u8>>, PersistenceError>;
+}
+
+/// Opens the backend selected by `kind` at `path`.
+///
+/// # Errors
+/// Returns [`PersistenceError`] when the underlying engine fails to open.
+pub fn open(
+    kind: PersistenceBackendKind,
+    path: &std::path::Path,
+    compression_level: i32,
+    min_read_conn: u32,
+    max_read_conn: u32,
+    max_bytes: Option<u64>,
+    metrics: Arc<Metrics>,
+) -> Result<Box<dyn PersistenceBackend>, PersistenceError> {
+    match kind {
+        PersistenceBackendKind::Sqlite => Ok(Box::new(super::persistent::PersistentCache::new(
+            path,
+            compression_level,
+            min_read_conn,
+            max_read_conn,
+            max_bytes,
+            metrics,
+        )?)),
+        // LMDB has no single-writer bottleneck to pool readers around (its
+        // own MVCC handles concurrent readers during a writer), so
+        // `min_read_conn`/`max_read_conn` don't apply here. It also has no
+        // size-bounded eviction counterpart to `max_bytes` yet.
+        PersistenceBackendKind::Lmdb => Ok(Box::new(super::lmdb::LmdbCache::new(
+            path,
+            compression_level,
+            metrics,
+        )?)),
+    }
+}
+
+/// Shared behavioral contract exercised against both backends, so a new
+/// engine can't silently diverge from the one `InMemoryCache` was written
+/// against. Not `#[test]` itself — each backend's own test module calls
+/// these against its concrete type.
+#[cfg(test)]
+pub(crate) mod contract {
+    use super::*;
+
+    pub(crate) fn assert_issue_roundtrip(backend: &dyn PersistenceBackend) {
+        assert!(backend.get_issue("PROJ-1").unwrap().is_none());
+
+        backend
+            .upsert_issue("PROJ-1", b"hello", Some("u1"))
+            .expect("upsert");
+        let got = backend
+            .get_issue("PROJ-1")
+            .expect("get")
+            .expect("row present");
+        assert_eq!(got.markdown, b"hello");
+        assert_eq!(got.updated.as_deref(), Some("u1"));
+        assert_eq!(got.content_hash, content_hash(b"hello"));
+        assert_eq!(got.version, 0, "non-atomic upsert_issue leaves version untouched");
+
+        backend.remove_issue("PROJ-1").expect("remove");
+        assert!(backend.get_issue("PROJ-1").unwrap().is_none());
+    }
+
+    pub(crate) fn assert_sync_cursor_roundtrip(backend: &dyn PersistenceBackend) {
+        assert!(backend.get_sync_cursor("PROJ").unwrap().is_none());
+
+        backend.set_sync_cursor("PROJ", "cursor-1").expect("set");
+        assert_eq!(
+            backend.get_sync_cursor("PROJ").unwrap().as_deref(),
+            Some("cursor-1")
+        );
+
+        backend.clear_sync_cursor("PROJ").expect("clear");
+        assert!(backend.get_sync_cursor("PROJ").unwrap().is_none());
+    }
+}
@@ -0,0 +1,644 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use heed::types::{Bytes, Str};
+use heed::{Database, Env, EnvOpenOptions};
+use serde::{Deserialize, Serialize};
+
+use crate::jira::IssueRef;
+use crate::metrics::Metrics;
+
+use super::backend::{self, PersistenceBackend, PersistenceError};
+use super::persistent::{PersistentIssue, PersistentIssueRow, PersistentSidecarRow, TicketIndexRow};
+
+const MAP_SIZE_BYTES: usize = 4 * 1024 * 1024 * 1024;
+const MAX_DBS: u32 = 8;
+
+const DB_ISSUES: &str = "issues";
+const DB_SYNC_CURSOR: &str = "sync_cursor";
+const DB_TICKET_INDEX: &str = "ticket_index";
+const DB_SIDECARS: &str = "issue_sidecars";
+const DB_BLOBS: &str = "blobs";
+const DB_QUERIES: &str = "queries";
+const DB_HISTORY: &str = "issue_history";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IssueRecord {
+    markdown: Vec<u8>,
+    updated: Option<String>,
+    #[serde(default)]
+    codec: u8,
+    #[serde(default)]
+    content_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketIndexRecord {
+    project: String,
+    updated_at: Option<String>,
+    path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SidecarRecord {
+    comments_md: Vec<u8>,
+    comments_jsonl: Vec<u8>,
+    #[serde(default)]
+    codec: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HistoryVersion {
+    version_id: String,
+    markdown: Vec<u8>,
+}
+
+/// LMDB-backed implementation of [`PersistenceBackend`], selected via
+/// `PersistenceBackendKind::Lmdb`. Trades `PersistentCache`'s richer index
+/// queries (`LIKE`-based prefix counts, `ORDER BY`) for lower write
+/// amplification on the hot issue-markdown upsert path, at the cost of
+/// doing those queries as full-database scans instead of indexed lookups —
+/// acceptable for the cache sizes this crate deals with.
+///
+/// Opens one environment at `path` (a directory, not a single file — LMDB
+/// manages its own data/lock files inside it) with one named database per
+/// logical table, mirroring `PersistentCache`'s SQLite tables. Row values
+/// are `rmp_serde`-encoded since LMDB itself is an opaque byte store.
+#[derive(Debug)]
+pub struct LmdbCache {
+    env: Env,
+    issues: Database<Str, Bytes>,
+    sync_cursor: Database<Str, Bytes>,
+    ticket_index: Database<Str, Bytes>,
+    sidecars: Database<Str, Bytes>,
+    blobs: Database<Str, Bytes>,
+    queries: Database<Str, Bytes>,
+    history: Database<Str, Bytes>,
+    compression_level: i32,
+    metrics: Arc<Metrics>,
+}
+
+impl LmdbCache {
+    /// Opens or creates the LMDB environment at `path`.
+    ///
+    /// # Errors
+    /// Returns [`PersistenceError`] when the environment or one of its
+    /// databases fails to open, or creating `path` fails.
+    pub fn new(path: &Path, compression_level: i32, metrics: Arc<Metrics>) -> Result<Self, PersistenceError> {
+        std::fs::create_dir_all(path)?;
+
+        // Safety: `map_size`/`max_dbs` are set before any reader or writer
+        // transaction is opened, satisfying `EnvOpenOptions::open`'s
+        // precondition that the environment isn't already in use by this
+        // process with a smaller map.
+        let env = unsafe {
+            EnvOpenOptions::new()
+                .map_size(MAP_SIZE_BYTES)
+                .max_dbs(MAX_DBS)
+                .open(path)?
+        };
+
+        let mut wtxn = env.write_txn()?;
+        let issues = env.create_database(&mut wtxn, Some(DB_ISSUES))?;
+        let sync_cursor = env.create_database(&mut wtxn, Some(DB_SYNC_CURSOR))?;
+        let ticket_index = env.create_database(&mut wtxn, Some(DB_TICKET_INDEX))?;
+        let sidecars = env.create_database(&mut wtxn, Some(DB_SIDECARS))?;
+        let blobs = env.create_database(&mut wtxn, Some(DB_BLOBS))?;
+        let queries = env.create_database(&mut wtxn, Some(DB_QUERIES))?;
+        let history = env.create_database(&mut wtxn, Some(DB_HISTORY))?;
+        wtxn.commit()?;
+
+        metrics.set_compression_level(compression_level);
+
+        Ok(Self {
+            env,
+            issues,
+            sync_cursor,
+            ticket_index,
+            sidecars,
+            blobs,
+            queries,
+            history,
+            compression_level,
+            metrics,
+        })
+    }
+
+    fn upsert_ticket_index_entry(
+        &self,
+        wtxn: &mut heed::RwTxn<'_>,
+        issue_key: &str,
+        updated: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        let project = project_from_issue_key(issue_key);
+        let path = format!("projects/{}/{}.md", project, issue_key);
+        let record = TicketIndexRecord {
+            project,
+            updated_at: updated.map(ToString::to_string),
+            path,
+        };
+        self.ticket_index
+            .put(wtxn, issue_key, &rmp_serde::to_vec(&record)?)?;
+        Ok(())
+    }
+
+    fn history_key(issue_key: &str, version_id: &str) -> String {
+        format!("{}\u{0}{}", issue_key, version_id)
+    }
+
+    /// Compresses a sidecar pair under one shared codec tag, mirroring
+    /// `PersistentCache::compress_sidecar_pair` — `compress()` always
+    /// returns `CODEC_ZSTD`, so both blobs necessarily share a codec.
+    fn compress_sidecar_pair(
+        &self,
+        comments_md: &[u8],
+        comments_jsonl: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>, u8), PersistenceError> {
+        let (md_codec, comments_md) = backend::compress(self.compression_level, comments_md, &self.metrics)?;
+        let (jsonl_codec, comments_jsonl) =
+            backend::compress(self.compression_level, comments_jsonl, &self.metrics)?;
+        debug_assert_eq!(md_codec, jsonl_codec, "compress() always returns CODEC_ZSTD");
+        Ok((comments_md, comments_jsonl, md_codec))
+    }
+}
+
+impl PersistenceBackend for LmdbCache {
+    fn get_issue(&self, issue_key: &str) -> Result<Option<PersistentIssue>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let Some(bytes) = self.issues.get(&rtxn, issue_key)? else {
+            return Ok(None);
+        };
+        let record: IssueRecord = rmp_serde::from_slice(bytes)?;
+        Ok(Some(PersistentIssue {
+            markdown: backend::decompress(record.codec, &record.markdown)?,
+            updated: record.updated,
+            content_hash: record.content_hash,
+            // LMDB has no `atomic_write`/`data_version` counterpart (that's
+            // SQLite-specific, see `PersistentCache::atomic_write`), so this
+            // backend never stamps a row with anything but the default.
+            version: 0,
+        }))
+    }
+
+    fn upsert_issue(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        updated: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        let content_hash = backend::content_hash(markdown);
+        let (codec, markdown) = backend::compress(self.compression_level, markdown, &self.metrics)?;
+        let record = IssueRecord {
+            markdown,
+            updated: updated.map(ToString::to_string),
+            codec,
+            content_hash,
+        };
+        self.issues
+            .put(&mut wtxn, issue_key, &rmp_serde::to_vec(&record)?)?;
+        self.upsert_ticket_index_entry(&mut wtxn, issue_key, updated)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn remove_issue(&self, issue_key: &str) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.issues.delete(&mut wtxn, issue_key)?;
+        self.ticket_index.delete(&mut wtxn, issue_key)?;
+        self.sidecars.delete(&mut wtxn, issue_key)?;
+
+        let stale_history_keys: Vec<String> = self
+            .history
+            .iter(&wtxn)?
+            .filter_map(Result::ok)
+            .map(|(key, _)| key.to_string())
+            .filter(|key| key.starts_with(&format!("{}\u{0}", issue_key)))
+            .collect();
+        for key in stale_history_keys {
+            self.history.delete(&mut wtxn, &key)?;
+        }
+
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn upsert_issues_batch(
+        &self,
+        issues: &[PersistentIssueRow],
+    ) -> Result<usize, PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        for (issue_key, markdown, updated) in issues {
+            let content_hash = backend::content_hash(markdown);
+            let (codec, markdown) = backend::compress(self.compression_level, markdown, &self.metrics)?;
+            let record = IssueRecord {
+                markdown,
+                updated: updated.clone(),
+                codec,
+                content_hash,
+            };
+            self.issues
+                .put(&mut wtxn, issue_key, &rmp_serde::to_vec(&record)?)?;
+            self.upsert_ticket_index_entry(&mut wtxn, issue_key, updated.as_deref())?;
+        }
+        wtxn.commit()?;
+        Ok(issues.len())
+    }
+
+    fn get_sync_cursor(&self, project: &str) -> Result<Option<String>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let Some(bytes) = self.sync_cursor.get(&rtxn, project)? else {
+            return Ok(None);
+        };
+        Ok(Some(String::from_utf8_lossy(bytes).into_owned()))
+    }
+
+    fn set_sync_cursor(&self, project: &str, last_sync: &str) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.sync_cursor
+            .put(&mut wtxn, project, last_sync.as_bytes())?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn clear_sync_cursor(&self, project: &str) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.sync_cursor.delete(&mut wtxn, project)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn cached_issue_count(&self, project_prefix: &str) -> Result<usize, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let prefix = format!("{}-", project_prefix);
+        let count = self
+            .issues
+            .iter(&rtxn)?
+            .filter_map(Result::ok)
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .count();
+        Ok(count)
+    }
+
+    fn issue_markdown_len(&self, issue_key: &str) -> Result<Option<u64>, PersistenceError> {
+        Ok(self.get_issue(issue_key)?.map(|issue| issue.markdown.len() as u64))
+    }
+
+    fn list_ticket_index(
+        &self,
+        projects: &[String],
+    ) -> Result<Vec<TicketIndexRow>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let mut out = Vec::new();
+        for entry in self.ticket_index.iter(&rtxn)? {
+            let (issue_key, bytes) = entry?;
+            let record: TicketIndexRecord = rmp_serde::from_slice(bytes)?;
+            if !projects.is_empty() && !projects.iter().any(|p| p == &record.project) {
+                continue;
+            }
+            out.push(TicketIndexRow {
+                id: issue_key.to_string(),
+                project: record.project,
+                updated_at: record.updated_at,
+                path: record.path,
+            });
+        }
+        out.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(out)
+    }
+
+    fn list_project_issue_refs(&self, project: &str) -> Result<Vec<IssueRef>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let mut out = Vec::new();
+        for entry in self.ticket_index.iter(&rtxn)? {
+            let (issue_key, bytes) = entry?;
+            let record: TicketIndexRecord = rmp_serde::from_slice(bytes)?;
+            if record.project != project {
+                continue;
+            }
+            out.push(IssueRef {
+                key: issue_key.to_string(),
+                updated: record.updated_at,
+            });
+        }
+        out.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(out)
+    }
+
+    // Iterates the whole index rather than using heed's range API: this
+    // backend's index is small enough in practice that the SQLite backend's
+    // indexed range scan is where the real win for large Jira instances is.
+    fn scan_ticket_index(
+        &self,
+        project: Option<&str>,
+        start_key: Option<&str>,
+        end_key: Option<&str>,
+        limit: usize,
+        reverse: bool,
+    ) -> Result<Vec<TicketIndexRow>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let mut out = Vec::new();
+        for entry in self.ticket_index.iter(&rtxn)? {
+            let (issue_key, bytes) = entry?;
+            if let Some(start) = start_key {
+                if issue_key < start {
+                    continue;
+                }
+            }
+            if let Some(end) = end_key {
+                if issue_key >= end {
+                    continue;
+                }
+            }
+            let record: TicketIndexRecord = rmp_serde::from_slice(bytes)?;
+            if let Some(project) = project {
+                if record.project != project {
+                    continue;
+                }
+            }
+            out.push(TicketIndexRow {
+                id: issue_key.to_string(),
+                project: record.project,
+                updated_at: record.updated_at,
+                path: record.path,
+            });
+        }
+        out.sort_by(|a, b| a.id.cmp(&b.id));
+        if reverse {
+            out.reverse();
+        }
+        out.truncate(limit);
+        Ok(out)
+    }
+
+    fn upsert_issue_sidecars(
+        &self,
+        issue_key: &str,
+        comments_md: &[u8],
+        comments_jsonl: &[u8],
+        _updated: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        let (comments_md, comments_jsonl, codec) =
+            self.compress_sidecar_pair(comments_md, comments_jsonl)?;
+        let record = SidecarRecord {
+            comments_md,
+            comments_jsonl,
+            codec,
+        };
+        self.sidecars
+            .put(&mut wtxn, issue_key, &rmp_serde::to_vec(&record)?)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn upsert_issue_sidecars_batch(
+        &self,
+        sidecars: &[PersistentSidecarRow],
+    ) -> Result<usize, PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        for (issue_key, comments_md, comments_jsonl, _updated) in sidecars {
+            let (comments_md, comments_jsonl, codec) =
+                self.compress_sidecar_pair(comments_md, comments_jsonl)?;
+            let record = SidecarRecord {
+                comments_md,
+                comments_jsonl,
+                codec,
+            };
+            self.sidecars
+                .put(&mut wtxn, issue_key, &rmp_serde::to_vec(&record)?)?;
+        }
+        wtxn.commit()?;
+        Ok(sidecars.len())
+    }
+
+    fn get_issue_comments_md(&self, issue_key: &str) -> Result<Option<Vec<u8>>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let Some(bytes) = self.sidecars.get(&rtxn, issue_key)? else {
+            return Ok(None);
+        };
+        let record: SidecarRecord = rmp_serde::from_slice(bytes)?;
+        Ok(Some(backend::decompress(record.codec, &record.comments_md)?))
+    }
+
+    fn get_issue_comments_jsonl(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<Vec<u8>>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let Some(bytes) = self.sidecars.get(&rtxn, issue_key)? else {
+            return Ok(None);
+        };
+        let record: SidecarRecord = rmp_serde::from_slice(bytes)?;
+        Ok(Some(backend::decompress(
+            record.codec,
+            &record.comments_jsonl,
+        )?))
+    }
+
+    fn issue_comments_md_len(&self, issue_key: &str) -> Result<Option<u64>, PersistenceError> {
+        Ok(self
+            .get_issue_comments_md(issue_key)?
+            .map(|bytes| bytes.len() as u64))
+    }
+
+    fn issue_comments_jsonl_len(&self, issue_key: &str) -> Result<Option<u64>, PersistenceError> {
+        Ok(self
+            .get_issue_comments_jsonl(issue_key)?
+            .map(|bytes| bytes.len() as u64))
+    }
+
+    fn get_blob(&self, key: &str) -> Result<Option<Vec<u8>>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        Ok(self.blobs.get(&rtxn, key)?.map(<[u8]>::to_vec))
+    }
+
+    fn set_blob(&self, key: &str, value: &[u8]) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.blobs.put(&mut wtxn, key, value)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn clear_blob(&self, key: &str) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.blobs.delete(&mut wtxn, key)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn list_queries(&self) -> Result<Vec<(String, String)>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let mut out = Vec::new();
+        for entry in self.queries.iter(&rtxn)? {
+            let (name, bytes) = entry?;
+            out.push((name.to_string(), String::from_utf8_lossy(bytes).into_owned()));
+        }
+        out.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(out)
+    }
+
+    fn upsert_query(&self, name: &str, jql: &str) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.queries.put(&mut wtxn, name, jql.as_bytes())?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn remove_query(&self, name: &str) -> Result<(), PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+        self.queries.delete(&mut wtxn, name)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    fn append_issue_history(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        max_versions: usize,
+    ) -> Result<String, PersistenceError> {
+        let mut wtxn = self.env.write_txn()?;
+
+        let version_id = unix_epoch_version_id();
+        let record = HistoryVersion {
+            version_id: version_id.clone(),
+            markdown: markdown.to_vec(),
+        };
+        self.history.put(
+            &mut wtxn,
+            &Self::history_key(issue_key, &version_id),
+            &rmp_serde::to_vec(&record)?,
+        )?;
+
+        let prefix = format!("{}\u{0}", issue_key);
+        let mut versions: Vec<(String, HistoryVersion)> = self
+            .history
+            .iter(&wtxn)?
+            .filter_map(Result::ok)
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, bytes)| {
+                let record: HistoryVersion = rmp_serde::from_slice(bytes)?;
+                Ok::<_, PersistenceError>((key.to_string(), record))
+            })
+            .collect::<Result<_, _>>()?;
+        versions.sort_by(|a, b| a.1.version_id.cmp(&b.1.version_id));
+
+        if versions.len() > max_versions {
+            for (key, _) in versions.drain(..versions.len() - max_versions) {
+                self.history.delete(&mut wtxn, &key)?;
+            }
+        }
+
+        wtxn.commit()?;
+        Ok(version_id)
+    }
+
+    fn list_issue_history_versions(&self, issue_key: &str) -> Result<Vec<String>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let prefix = format!("{}\u{0}", issue_key);
+        let mut versions: Vec<String> = self
+            .history
+            .iter(&rtxn)?
+            .filter_map(Result::ok)
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, bytes)| {
+                let record: HistoryVersion = rmp_serde::from_slice(bytes)?;
+                Ok::<_, PersistenceError>(record.version_id)
+            })
+            .collect::<Result<_, _>>()?;
+        versions.sort();
+        Ok(versions)
+    }
+
+    fn get_issue_history_version(
+        &self,
+        issue_key: &str,
+        version_id: &str,
+    ) -> Result<Option<Vec<u8>>, PersistenceError> {
+        let rtxn = self.env.read_txn()?;
+        let Some(bytes) = self
+            .history
+            .get(&rtxn, &Self::history_key(issue_key, version_id))?
+        else {
+            return Ok(None);
+        };
+        let record: HistoryVersion = rmp_serde::from_slice(bytes)?;
+        Ok(Some(record.markdown))
+    }
+}
+
+/// Monotonic-enough version id for LMDB history snapshots: unix
+/// milliseconds. Unlike `PersistentCache::iso8601_version_id`, this can't
+/// lean on SQLite's `strftime` (there's no SQL engine here), and this crate
+/// has no `chrono` dependency to format a calendar timestamp in pure Rust.
+fn unix_epoch_version_id() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
+
+fn project_from_issue_key(issue_key: &str) -> String {
+    issue_key
+        .split_once('-')
+        .map(|(project, _)| project.to_string())
+        .unwrap_or_else(|| "UNKNOWN".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_tmp() -> (LmdbCache, tempfile::TempDir) {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cache = LmdbCache::new(
+            dir.path(),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            Arc::new(Metrics::new()),
+        )
+        .expect("lmdb open");
+        (cache, dir)
+    }
+
+    #[test]
+    fn lmdb_backend_satisfies_issue_roundtrip_contract() {
+        let (db, _dir) = open_tmp();
+        super::super::backend::contract::assert_issue_roundtrip(&db);
+    }
+
+    #[test]
+    fn lmdb_backend_satisfies_sync_cursor_contract() {
+        let (db, _dir) = open_tmp();
+        super::super::backend::contract::assert_sync_cursor_roundtrip(&db);
+    }
+
+    #[test]
+    fn history_ring_trims_to_max_versions() {
+        let (db, _dir) = open_tmp();
+        let first = db.append_issue_history("PROJ-1", b"v1", 2).expect("v1");
+        db.append_issue_history("PROJ-1", b"v2", 2).expect("v2");
+        let third = db.append_issue_history("PROJ-1", b"v3", 2).expect("v3");
+
+        let versions = db.list_issue_history_versions("PROJ-1").expect("list");
+        assert_eq!(versions.len(), 2);
+        assert!(!versions.contains(&first));
+
+        let latest = db
+            .get_issue_history_version("PROJ-1", &third)
+            .expect("get")
+            .expect("present");
+        assert_eq!(latest, b"v3");
+    }
+
+    #[test]
+    fn compressed_markdown_roundtrips_with_large_repetitive_content() {
+        let (db, _dir) = open_tmp();
+        let markdown = "line of fairly repetitive markdown text\n".repeat(200);
+        db.upsert_issue("PROJ-1", markdown.as_bytes(), Some("u1"))
+            .expect("upsert");
+        let got = db.get_issue("PROJ-1").expect("read").expect("present");
+        assert_eq!(got.markdown, markdown.as_bytes());
+    }
+}
@@ -1,11 +1,17 @@
 use std::path::Path;
-use std::sync::{Mutex, MutexGuard};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-use rusqlite::{params, Connection};
+use r2d2::{Pool, PooledConnection};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use uuid::Uuid;
 
 use crate::jira::IssueRef;
 use crate::logging;
+use crate::metrics::Metrics;
+
+use super::backend;
 
 pub type PersistentIssueRow = (String, Vec<u8>, Option<String>);
 pub type PersistentSidecarRow = (String, Vec<u8>, Vec<u8>, Option<String>);
@@ -15,6 +21,54 @@ pub type PersistentSidecarRow = (String, Vec<u8>, Vec<u8>, Option<String>);
 pub struct PersistentIssue {
     pub markdown: Vec<u8>,
     pub updated: Option<String>,
+    /// Hex-encoded BLAKE3 over `markdown`, see [`backend::content_hash`].
+    /// Carried forward so a warm start from persistence can skip a redundant
+    /// rewrite without re-fetching from Jira first.
+    pub content_hash: String,
+    /// Database-wide version this row was last stamped with by
+    /// [`PersistentCache::atomic_write`]; `0` for rows never touched by it.
+    /// Round-trip this as `expected_version` on the next `atomic_write` to
+    /// detect a concurrent writer racing ahead of the caller.
+    pub version: u64,
+}
+
+#[derive(Debug, Clone)]
+/// One mutation applied by [`PersistentCache::atomic_write`] once all of its
+/// checks pass.
+pub enum AtomicMutation {
+    UpsertIssue {
+        issue_key: String,
+        markdown: Vec<u8>,
+        updated: Option<String>,
+    },
+    DeleteIssue {
+        issue_key: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+/// One journaled column-level mutation, as recorded or replayed by
+/// [`PersistentCache::export_changes`]/[`PersistentCache::apply_changes`].
+pub struct Change {
+    pub seq: i64,
+    pub site_id: String,
+    pub issue_key: String,
+    pub column: String,
+    pub value: Vec<u8>,
+    pub ts: i64,
+}
+
+#[derive(Debug, Clone)]
+/// One outbound local mutation not yet confirmed pushed to Jira; see
+/// [`PersistentCache::enqueue_write`].
+pub struct PendingWrite {
+    pub id: i64,
+    pub issue_key: String,
+    pub kind: String,
+    pub payload: Vec<u8>,
+    pub enqueued_at: String,
+    pub attempts: i64,
+    pub visible_at: String,
 }
 
 #[derive(Debug, Clone)]
@@ -28,17 +82,73 @@ pub struct TicketIndexRow {
 
 #[derive(Debug)]
 /// SQLite-backed cache for issue content and sync metadata.
+///
+/// SQLite only ever allows one writer transaction at a time regardless of
+/// how many connections are open, so `writer_pool` is capped at a single
+/// connection — a pool of one rather than a bare `Mutex<Connection>`, so
+/// checkout goes through r2d2's own synchronization instead of a mutex a
+/// panicking writer could poison. Read-only methods check out a connection
+/// from `read_pool` instead, so a long `upsert_issues_batch` doesn't block
+/// concurrent `get_issue`/`list_ticket_index` lookups the filesystem needs
+/// to serve.
 pub struct PersistentCache {
-    conn: Mutex<Connection>,
+    writer_pool: Pool<SqliteConnectionManager>,
+    read_pool: Pool<SqliteConnectionManager>,
+    /// zstd level applied to markdown and comment sidecars on write; see
+    /// [`backend::DEFAULT_COMPRESSION_LEVEL`].
+    compression_level: i32,
+    /// On-disk budget enforced by [`Self::enforce_cache_budget`], run
+    /// automatically after batch upserts; `None` means unbounded.
+    max_bytes: Option<u64>,
+    /// Stable identity for this database, generated on first open and
+    /// persisted in `blobs`; see [`Self::export_changes`].
+    site_id: String,
+    metrics: Arc<Metrics>,
 }
 
 impl PersistentCache {
-    /// Opens or creates the persistent cache database.
+    /// Opens or creates the persistent cache database, in WAL mode with
+    /// `synchronous = NORMAL` (safe under WAL: only a power loss, not a
+    /// process crash, can lose the last commit). `min_conn`/`max_conn` size
+    /// the pool of connections reserved for read methods, alongside the
+    /// single dedicated writer connection (see the type-level docs for why
+    /// writers aren't pooled beyond that one connection). `max_bytes` caps
+    /// on-disk markdown/sidecar footprint; see [`Self::enforce_cache_budget`].
     ///
     /// # Errors
-    /// Returns [`rusqlite::Error`] when opening or initializing SQLite fails.
-    pub fn new(path: &Path) -> Result<Self, rusqlite::Error> {
-        let conn = Connection::open(path)?;
+    /// Returns [`rusqlite::Error`] when opening or initializing SQLite fails,
+    /// or when either pool can't be built.
+    pub fn new(
+        path: &Path,
+        compression_level: i32,
+        min_conn: u32,
+        max_conn: u32,
+        max_bytes: Option<u64>,
+        metrics: Arc<Metrics>,
+    ) -> Result<Self, rusqlite::Error> {
+        let uri = shared_cache_uri(path);
+
+        // Built first, and kept alive for the cache's whole lifetime: for
+        // `:memory:`'s shared-cache URI, at least one open connection is
+        // what keeps the in-memory database from vanishing once the
+        // schema-setup connection that created it would otherwise close.
+        let writer_manager = SqliteConnectionManager::file(&uri).with_flags(
+            OpenFlags::SQLITE_OPEN_READ_WRITE
+                | OpenFlags::SQLITE_OPEN_CREATE
+                | OpenFlags::SQLITE_OPEN_URI,
+        );
+        let writer_pool = Pool::builder()
+            .max_size(1)
+            .build(writer_manager)
+            .map_err(pool_err_to_sqlite)?;
+
+        let conn = writer_pool.get().map_err(pool_err_to_sqlite)?;
+        // No-op (SQLite reports back "memory") for `:memory:`'s shared-cache
+        // URI, since in-memory databases can't use a separate WAL file; real
+        // on-disk databases get the concurrent-readers-during-a-writer
+        // behavior the read pool depends on.
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
         conn.execute_batch(
             "
 CREATE TABLE IF NOT EXISTS issues (
@@ -46,7 +156,9 @@ CREATE TABLE IF NOT EXISTS issues (
   markdown BLOB NOT NULL,
   updated TEXT,
   cached_at TEXT NOT NULL,
-  access_count INTEGER NOT NULL DEFAULT 0
+  access_count INTEGER NOT NULL DEFAULT 0,
+  codec INTEGER NOT NULL DEFAULT 0,
+  content_hash TEXT NOT NULL DEFAULT ''
 );
 
 CREATE TABLE IF NOT EXISTS sync_cursor (
@@ -69,9 +181,62 @@ CREATE TABLE IF NOT EXISTS issue_sidecars (
   comments_md BLOB NOT NULL,
   comments_jsonl BLOB NOT NULL,
   updated TEXT,
-  cached_at TEXT NOT NULL
+  cached_at TEXT NOT NULL,
+  codec INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS blobs (
+  key TEXT PRIMARY KEY,
+  value BLOB NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS queries (
+  name TEXT PRIMARY KEY,
+  jql TEXT NOT NULL,
+  created_at TEXT NOT NULL
 );
 
+CREATE TABLE IF NOT EXISTS issue_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  issue_key TEXT NOT NULL,
+  version_id TEXT NOT NULL,
+  markdown BLOB NOT NULL,
+  saved_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_issue_history_issue_key ON issue_history(issue_key);
+
+CREATE TABLE IF NOT EXISTS data_version (
+  k INTEGER PRIMARY KEY,
+  version INTEGER NOT NULL
+);
+
+INSERT OR IGNORE INTO data_version(k, version) VALUES (0, 0);
+
+CREATE TABLE IF NOT EXISTS pending_writes (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  issue_key TEXT NOT NULL,
+  kind TEXT NOT NULL,
+  payload BLOB NOT NULL,
+  enqueued_at TEXT NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  visible_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_pending_writes_visible_at ON pending_writes(visible_at);
+
+CREATE TABLE IF NOT EXISTS changes (
+  seq INTEGER PRIMARY KEY AUTOINCREMENT,
+  site_id TEXT NOT NULL,
+  issue_key TEXT NOT NULL,
+  column TEXT NOT NULL,
+  value BLOB NOT NULL,
+  ts INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_changes_issue_key_column ON changes(issue_key, column);
+
 INSERT OR IGNORE INTO ticket_index(issue_key, project, updated_at, path, last_indexed_at)
 SELECT
   issue_key,
@@ -91,18 +256,95 @@ FROM issues;
  ",
         )?;
 
+        // `CREATE TABLE IF NOT EXISTS` doesn't retrofit new columns onto a
+        // database created before `codec` existed; add it defensively and
+        // ignore the "duplicate column" error on databases that already
+        // have it (fresh databases, via the `CREATE TABLE` above).
+        for stmt in [
+            "ALTER TABLE issues ADD COLUMN codec INTEGER NOT NULL DEFAULT 0",
+            "ALTER TABLE issue_sidecars ADD COLUMN codec INTEGER NOT NULL DEFAULT 0",
+            "ALTER TABLE issues ADD COLUMN content_hash TEXT NOT NULL DEFAULT ''",
+            "ALTER TABLE issues ADD COLUMN version INTEGER NOT NULL DEFAULT 0",
+            "ALTER TABLE issue_sidecars ADD COLUMN version INTEGER NOT NULL DEFAULT 0",
+        ] {
+            if let Err(err) = conn.execute(stmt, []) {
+                if !err.to_string().contains("duplicate column name") {
+                    return Err(err);
+                }
+            }
+        }
+
+        // Stable per-database identity for change-log merge; reuse the
+        // generic `blobs` KV table rather than a bespoke single-row one.
+        let site_id: Option<Vec<u8>> = conn
+            .query_row("SELECT value FROM blobs WHERE key = 'site_id'", [], |row| row.get(0))
+            .optional()?;
+        let site_id = match site_id {
+            Some(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            None => {
+                let generated = Uuid::new_v4().to_string();
+                conn.execute(
+                    "INSERT INTO blobs(key, value, updated_at) VALUES ('site_id', ?1, ?2)",
+                    params![generated.as_bytes(), unix_epoch_seconds_string()],
+                )?;
+                generated
+            }
+        };
+
+        metrics.set_compression_level(compression_level);
+        // Done with the writer-pool connection borrowed for schema setup;
+        // the pool (capped at 1) keeps it open for the cache's lifetime.
+        drop(conn);
+
+        // Read-write, not `SQLITE_OPEN_READ_ONLY`: `get_issue` bumps
+        // `access_count` on every read, and pool connections carry that
+        // small write too rather than routing it back through the single
+        // writer connection this pool exists to avoid contending with.
+        let read_manager = SqliteConnectionManager::file(&uri)
+            .with_flags(OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_URI);
+        let read_pool = Pool::builder()
+            .min_idle(Some(min_conn))
+            .max_size(max_conn)
+            .build(read_manager)
+            .map_err(pool_err_to_sqlite)?;
+        metrics.set_read_pool_size(max_conn);
+
         Ok(Self {
-            conn: Mutex::new(conn),
+            writer_pool,
+            read_pool,
+            compression_level,
+            max_bytes,
+            site_id,
+            metrics,
         })
     }
 
+    /// Checks out the dedicated writer connection; blocks until it's free
+    /// since `writer_pool` is capped at one connection (see the type-level
+    /// docs for why writes aren't pooled beyond that one).
+    fn write_conn(&self) -> Result<PooledConnection<SqliteConnectionManager>, rusqlite::Error> {
+        self.writer_pool.get().map_err(pool_err_to_sqlite)
+    }
+
+    /// Checks out a pooled read connection, reporting the wait to
+    /// `Metrics` so pool contention shows up alongside the other cache
+    /// gauges.
+    fn read_conn(&self) -> Result<PooledConnection<SqliteConnectionManager>, rusqlite::Error> {
+        let started = Instant::now();
+        let conn = self.read_pool.get().map_err(pool_err_to_sqlite)?;
+        self.metrics.observe_read_pool_checkout_wait(started.elapsed());
+        Ok(conn)
+    }
+
     /// Loads one persisted issue and increments its access counter.
     ///
     /// # Errors
     /// Returns [`rusqlite::Error`] when query or update execution fails.
     pub fn get_issue(&self, issue_key: &str) -> Result<Option<PersistentIssue>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
-        let mut stmt = conn.prepare("SELECT markdown, updated FROM issues WHERE issue_key = ?1")?;
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT markdown, updated, codec, content_hash, version FROM issues WHERE issue_key = ?1",
+        )?;
         let mut rows = stmt.query(params![issue_key])?;
 
         if let Some(row) = rows.next()? {
@@ -111,15 +353,41 @@ FROM issues;
                 params![issue_key],
             )?;
 
+            let raw: Vec<u8> = row.get(0)?;
+            let codec: u8 = row.get(2)?;
+            let markdown = backend::decompress(codec, &raw).map_err(persistence_err_to_sqlite)?;
+            let version: i64 = row.get(4)?;
+
             return Ok(Some(PersistentIssue {
-                markdown: row.get(0)?,
+                markdown,
                 updated: row.get(1)?,
+                content_hash: row.get(3)?,
+                version: version as u64,
             }));
         }
 
         Ok(None)
     }
 
+    /// Decompressed markdown length without bumping `access_count`, unlike
+    /// [`Self::get_issue`] — used where we only need the size (e.g. FUSE
+    /// `getattr`), not to record a real read.
+    fn peek_markdown_len(&self, issue_key: &str) -> Result<Option<u64>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt =
+            conn.prepare("SELECT markdown, codec FROM issues WHERE issue_key = ?1")?;
+        let mut rows = stmt.query(params![issue_key])?;
+
+        if let Some(row) = rows.next()? {
+            let raw: Vec<u8> = row.get(0)?;
+            let codec: u8 = row.get(1)?;
+            let markdown = backend::decompress(codec, &raw).map_err(persistence_err_to_sqlite)?;
+            return Ok(Some(markdown.len() as u64));
+        }
+
+        Ok(None)
+    }
+
     /// Upserts one issue markdown payload.
     ///
     /// # Errors
@@ -131,20 +399,73 @@ FROM issues;
         updated: Option<&str>,
     ) -> Result<(), rusqlite::Error> {
         let now = unix_epoch_seconds_string();
-        let conn = lock_conn_or_recover(&self.conn);
+        let ts = unix_epoch_seconds();
+        let content_hash = backend::content_hash(markdown);
+        let (codec, compressed) = backend::compress(self.compression_level, markdown, &self.metrics)
+            .map_err(persistence_err_to_sqlite)?;
+        let conn = self.write_conn()?;
         conn.execute(
             "
-INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count)
-VALUES (?1, ?2, ?3, ?4, 1)
+INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count, codec, content_hash)
+VALUES (?1, ?2, ?3, ?4, 1, ?5, ?6)
 ON CONFLICT(issue_key) DO UPDATE SET
   markdown = excluded.markdown,
   updated = excluded.updated,
   cached_at = excluded.cached_at,
-  access_count = issues.access_count + 1
+  access_count = issues.access_count + 1,
+  codec = excluded.codec,
+  content_hash = excluded.content_hash
 ",
-            params![issue_key, markdown, updated, now],
+            params![issue_key, compressed, updated, now, codec, content_hash],
         )?;
         upsert_ticket_index(&conn, issue_key, updated, &now)?;
+        self.record_change(&conn, issue_key, "markdown", markdown, ts)?;
+        if let Some(updated) = updated {
+            self.record_change(&conn, issue_key, "updated", updated.as_bytes(), ts)?;
+        }
+        Ok(())
+    }
+
+    /// Appends a row to the `changes` journal recording a column-level write,
+    /// under this database's [`Self::site_id`]; see [`Self::export_changes`].
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    fn record_change(
+        &self,
+        conn: &Connection,
+        issue_key: &str,
+        column: &str,
+        value: &[u8],
+        ts: i64,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT INTO changes(site_id, issue_key, column, value, ts) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![self.site_id, issue_key, column, value, ts],
+        )?;
+        Ok(())
+    }
+
+    /// Drops an issue no longer present in Jira: its markdown, ticket index
+    /// row, comment sidecars, and history versions.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn remove_issue(&self, issue_key: &str) -> Result<(), rusqlite::Error> {
+        let conn = self.write_conn()?;
+        conn.execute("DELETE FROM issues WHERE issue_key = ?1", params![issue_key])?;
+        conn.execute(
+            "DELETE FROM ticket_index WHERE issue_key = ?1",
+            params![issue_key],
+        )?;
+        conn.execute(
+            "DELETE FROM issue_sidecars WHERE issue_key = ?1",
+            params![issue_key],
+        )?;
+        conn.execute(
+            "DELETE FROM issue_history WHERE issue_key = ?1",
+            params![issue_key],
+        )?;
         Ok(())
     }
 
@@ -157,37 +478,432 @@ ON CONFLICT(issue_key) DO UPDATE SET
         issues: &[PersistentIssueRow],
     ) -> Result<usize, rusqlite::Error> {
         let now = unix_epoch_seconds_string();
-        let mut conn = lock_conn_or_recover(&self.conn);
+        let mut conn = self.write_conn()?;
         let tx = conn.transaction()?;
 
         let mut count = 0;
         for (issue_key, markdown, updated) in issues {
+            let content_hash = backend::content_hash(markdown);
+            let (codec, compressed) = backend::compress(self.compression_level, markdown, &self.metrics)
+                .map_err(persistence_err_to_sqlite)?;
             tx.execute(
                 "
-INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count)
-VALUES (?1, ?2, ?3, ?4, 1)
+INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count, codec, content_hash)
+VALUES (?1, ?2, ?3, ?4, 1, ?5, ?6)
 ON CONFLICT(issue_key) DO UPDATE SET
   markdown = excluded.markdown,
   updated = excluded.updated,
   cached_at = excluded.cached_at,
-  access_count = issues.access_count + 1
+  access_count = issues.access_count + 1,
+  codec = excluded.codec,
+  content_hash = excluded.content_hash
 ",
-                params![issue_key, markdown, updated, now],
+                params![issue_key, compressed, updated, now, codec, content_hash],
             )?;
             upsert_ticket_index(&tx, issue_key, updated.as_deref(), &now)?;
             count += 1;
         }
 
         tx.commit()?;
+
+        if let Some(max_bytes) = self.max_bytes {
+            let (evicted, reclaimed) = self.enforce_cache_budget(max_bytes)?;
+            if evicted > 0 {
+                logging::info(format!(
+                    "cache budget: evicted {} issue(s), reclaimed {} bytes",
+                    evicted, reclaimed
+                ));
+            }
+        }
+
         Ok(count)
     }
 
+    /// Evicts the lowest-value issues (least frequently, then least recently
+    /// accessed — ascending `access_count`, then oldest `cached_at`) until
+    /// the on-disk footprint of `issues.markdown` plus `issue_sidecars`'
+    /// `comments_md`/`comments_jsonl` is at or under `max_bytes`. Eviction
+    /// cascades to `issue_sidecars`, `ticket_index`, and `issue_history` for
+    /// the same `issue_key`, matching [`Self::remove_issue`]'s cascade.
+    ///
+    /// Returns the number of issues evicted and the bytes reclaimed, for
+    /// logging.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when transaction or SQL execution fails.
+    pub fn enforce_cache_budget(&self, max_bytes: u64) -> Result<(usize, u64), rusqlite::Error> {
+        let mut conn = self.write_conn()?;
+        let tx = conn.transaction()?;
+
+        let mut evicted = 0usize;
+        let mut reclaimed = 0u64;
+        loop {
+            let total: i64 = tx.query_row(
+                "
+SELECT
+  COALESCE((SELECT SUM(length(markdown)) FROM issues), 0) +
+  COALESCE((SELECT SUM(length(comments_md) + length(comments_jsonl)) FROM issue_sidecars), 0)
+",
+                [],
+                |row| row.get(0),
+            )?;
+            if total as u64 <= max_bytes {
+                break;
+            }
+
+            let victim: Option<(String, i64)> = tx
+                .query_row(
+                    "SELECT issue_key, length(markdown) FROM issues ORDER BY access_count ASC, cached_at ASC LIMIT 1",
+                    [],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()?;
+            let Some((issue_key, markdown_len)) = victim else {
+                // Nothing left in `issues` but still over budget (e.g. an
+                // orphaned sidecar row): nothing more we can evict.
+                break;
+            };
+
+            let sidecar_len: i64 = tx
+                .query_row(
+                    "SELECT length(comments_md) + length(comments_jsonl) FROM issue_sidecars WHERE issue_key = ?1",
+                    params![issue_key],
+                    |row| row.get(0),
+                )
+                .optional()?
+                .unwrap_or(0);
+
+            tx.execute("DELETE FROM issues WHERE issue_key = ?1", params![issue_key])?;
+            tx.execute(
+                "DELETE FROM issue_sidecars WHERE issue_key = ?1",
+                params![issue_key],
+            )?;
+            tx.execute(
+                "DELETE FROM ticket_index WHERE issue_key = ?1",
+                params![issue_key],
+            )?;
+            tx.execute(
+                "DELETE FROM issue_history WHERE issue_key = ?1",
+                params![issue_key],
+            )?;
+
+            evicted += 1;
+            reclaimed += (markdown_len + sidecar_len) as u64;
+        }
+
+        tx.commit()?;
+        Ok((evicted, reclaimed))
+    }
+
+    /// Check-and-set write for callers that need to detect a concurrent
+    /// writer racing ahead of them (e.g. pushing a front-matter edit back to
+    /// Jira without clobbering a sync that landed in between). Every check in
+    /// `checks` is compared against `issues.version` inside one transaction
+    /// before any mutation runs; `expected_version: None` means "this issue
+    /// must not exist yet". On the first mismatch the transaction is dropped
+    /// without being committed (SQLite rolls it back) and this returns
+    /// `Ok(None)`. On success every mutated issue is stamped with the new
+    /// database-wide version and this returns `Ok(Some(new_version))`.
+    ///
+    /// SQLite-only: there's no LMDB counterpart, since LMDB's MVCC doesn't
+    /// need a versionstamp to detect this kind of race the way SQLite's
+    /// shared-writer model does.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when transaction or SQL execution fails.
+    pub fn atomic_write(
+        &self,
+        checks: &[(String, Option<u64>)],
+        mutations: &[AtomicMutation],
+    ) -> Result<Option<u64>, rusqlite::Error> {
+        let now = unix_epoch_seconds_string();
+        let mut conn = self.write_conn()?;
+        let tx = conn.transaction()?;
+
+        for (issue_key, expected_version) in checks {
+            let actual: Option<i64> = tx
+                .query_row(
+                    "SELECT version FROM issues WHERE issue_key = ?1",
+                    params![issue_key],
+                    |row| row.get(0),
+                )
+                .optional()?;
+
+            let matches = match (actual, expected_version) {
+                (None, None) => true,
+                (Some(actual), Some(expected)) => actual as u64 == *expected,
+                _ => false,
+            };
+            if !matches {
+                return Ok(None);
+            }
+        }
+
+        let new_version: i64 = tx.query_row(
+            "UPDATE data_version SET version = version + 1 WHERE k = 0 RETURNING version",
+            [],
+            |row| row.get(0),
+        )?;
+
+        for mutation in mutations {
+            match mutation {
+                AtomicMutation::UpsertIssue { issue_key, markdown, updated } => {
+                    let content_hash = backend::content_hash(markdown);
+                    let (codec, compressed) =
+                        backend::compress(self.compression_level, markdown, &self.metrics)
+                            .map_err(persistence_err_to_sqlite)?;
+                    tx.execute(
+                        "
+INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count, codec, content_hash, version)
+VALUES (?1, ?2, ?3, ?4, 1, ?5, ?6, ?7)
+ON CONFLICT(issue_key) DO UPDATE SET
+  markdown = excluded.markdown,
+  updated = excluded.updated,
+  cached_at = excluded.cached_at,
+  access_count = issues.access_count + 1,
+  codec = excluded.codec,
+  content_hash = excluded.content_hash,
+  version = excluded.version
+",
+                        params![issue_key, compressed, updated, now, codec, content_hash, new_version],
+                    )?;
+                    upsert_ticket_index(&tx, issue_key, updated.as_deref(), &now)?;
+                }
+                AtomicMutation::DeleteIssue { issue_key } => {
+                    tx.execute("DELETE FROM issues WHERE issue_key = ?1", params![issue_key])?;
+                    tx.execute(
+                        "DELETE FROM ticket_index WHERE issue_key = ?1",
+                        params![issue_key],
+                    )?;
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(Some(new_version as u64))
+    }
+
+    /// Durably enqueues a local mutation to replay against Jira once
+    /// connectivity returns, making the cache a store-and-forward buffer for
+    /// offline edits rather than a read-only mirror. Returns the new row's
+    /// `id`, used later by [`Self::ack_write`]/[`Self::defer_write`].
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn enqueue_write(
+        &self,
+        issue_key: &str,
+        kind: &str,
+        payload: &[u8],
+    ) -> Result<i64, rusqlite::Error> {
+        let now = unix_epoch_seconds_string();
+        let conn = self.write_conn()?;
+        conn.execute(
+            "
+INSERT INTO pending_writes(issue_key, kind, payload, enqueued_at, attempts, visible_at)
+VALUES (?1, ?2, ?3, ?4, 0, ?4)
+",
+            params![issue_key, kind, payload, now],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Lists queued writes ready to replay as of `now` (a unix-epoch-seconds
+    /// string, matching [`Self::enqueue_write`]'s `enqueued_at`), oldest
+    /// first. Doesn't remove or lock the rows returned — callers ack or defer
+    /// each one explicitly after attempting it, so a crash mid-replay just
+    /// means the same rows are dequeued again next time.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn dequeue_ready(&self, now: &str, limit: usize) -> Result<Vec<PendingWrite>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare(
+            "
+SELECT id, issue_key, kind, payload, enqueued_at, attempts, visible_at
+FROM pending_writes
+WHERE visible_at <= ?1
+ORDER BY id ASC
+LIMIT ?2
+",
+        )?;
+        let mut rows = stmt.query(params![now, limit as i64])?;
+        let mut out = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            out.push(PendingWrite {
+                id: row.get(0)?,
+                issue_key: row.get(1)?,
+                kind: row.get(2)?,
+                payload: row.get(3)?,
+                enqueued_at: row.get(4)?,
+                attempts: row.get(5)?,
+                visible_at: row.get(6)?,
+            });
+        }
+
+        Ok(out)
+    }
+
+    /// Drops a queued write once it's been successfully replayed against Jira.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn ack_write(&self, id: i64) -> Result<(), rusqlite::Error> {
+        let conn = self.write_conn()?;
+        conn.execute("DELETE FROM pending_writes WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Bumps `attempts` and pushes `visible_at` forward by `backoff_secs` from
+    /// now, so a failed replay attempt backs off exponentially instead of
+    /// being retried immediately by the next [`Self::dequeue_ready`] call.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn defer_write(&self, id: i64, backoff_secs: u64) -> Result<(), rusqlite::Error> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        let visible_at = (now + backoff_secs).to_string();
+        let conn = self.write_conn()?;
+        conn.execute(
+            "UPDATE pending_writes SET attempts = attempts + 1, visible_at = ?2 WHERE id = ?1",
+            params![id, visible_at],
+        )?;
+        Ok(())
+    }
+
+    /// Exports journaled changes with `seq` strictly greater than `since`
+    /// (everything, if `since` is `None`), in `seq` order. Two caches
+    /// converge by exchanging these beyond each other's high-water mark and
+    /// feeding the result to [`Self::apply_changes`].
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn export_changes(&self, since: Option<i64>) -> Result<Vec<Change>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare(
+            "
+SELECT seq, site_id, issue_key, column, value, ts
+FROM changes
+WHERE (?1 IS NULL OR seq > ?1)
+ORDER BY seq ASC
+",
+        )?;
+        let mut rows = stmt.query(params![since])?;
+        let mut out = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            out.push(Change {
+                seq: row.get(0)?,
+                site_id: row.get(1)?,
+                issue_key: row.get(2)?,
+                column: row.get(3)?,
+                value: row.get(4)?,
+                ts: row.get(5)?,
+            });
+        }
+
+        Ok(out)
+    }
+
+    /// Merges change rows exported from another cache (see
+    /// [`Self::export_changes`]) into this one, last-writer-wins per
+    /// `(issue_key, column)`: an incoming change is applied only if its
+    /// `(ts, site_id)` is strictly greater than the winner already on file,
+    /// with `site_id` as a deterministic tiebreaker when two sites wrote at
+    /// the same second. Applied changes are themselves journaled, so a third
+    /// cache merging from this one later sees them too.
+    ///
+    /// Applied `markdown`/`comments_md`/`comments_jsonl` values are written
+    /// back as `CODEC_RAW` rather than recompressed, since `Change::value`
+    /// holds plaintext and a merge may touch only one half of a sidecar
+    /// pair; the row gets recompressed to the configured zstd level next
+    /// time a direct local upsert touches it.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn apply_changes(&self, changes: &[Change]) -> Result<(), rusqlite::Error> {
+        let mut conn = self.write_conn()?;
+        let tx = conn.transaction()?;
+
+        for change in changes {
+            let current: Option<(i64, String)> = tx
+                .query_row(
+                    "
+SELECT ts, site_id FROM changes
+WHERE issue_key = ?1 AND column = ?2
+ORDER BY ts DESC, site_id DESC
+LIMIT 1
+",
+                    params![change.issue_key, change.column],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()?;
+
+            let superseded = match &current {
+                Some((local_ts, local_site_id)) => {
+                    (change.ts, &change.site_id) <= (*local_ts, local_site_id)
+                }
+                None => false,
+            };
+            if superseded {
+                continue;
+            }
+
+            tx.execute(
+                "INSERT INTO changes(site_id, issue_key, column, value, ts) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![change.site_id, change.issue_key, change.column, change.value, change.ts],
+            )?;
+
+            match change.column.as_str() {
+                "markdown" => {
+                    tx.execute(
+                        "UPDATE issues SET markdown = ?2, codec = 0, content_hash = ?3 WHERE issue_key = ?1",
+                        params![change.issue_key, change.value, backend::content_hash(&change.value)],
+                    )?;
+                }
+                "comments_md" => {
+                    tx.execute(
+                        "UPDATE issue_sidecars SET comments_md = ?2, codec = 0 WHERE issue_key = ?1",
+                        params![change.issue_key, change.value],
+                    )?;
+                }
+                "comments_jsonl" => {
+                    tx.execute(
+                        "UPDATE issue_sidecars SET comments_jsonl = ?2, codec = 0 WHERE issue_key = ?1",
+                        params![change.issue_key, change.value],
+                    )?;
+                }
+                "updated" => {
+                    let updated = String::from_utf8_lossy(&change.value).into_owned();
+                    tx.execute(
+                        "UPDATE issues SET updated = ?2 WHERE issue_key = ?1",
+                        params![change.issue_key, updated],
+                    )?;
+                    tx.execute(
+                        "UPDATE issue_sidecars SET updated = ?2 WHERE issue_key = ?1",
+                        params![change.issue_key, updated],
+                    )?;
+                }
+                _ => {
+                    logging::warn(format!("apply_changes: unknown journaled column {:?}", change.column));
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
     /// Reads the last sync cursor for a project.
     ///
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn get_sync_cursor(&self, project: &str) -> Result<Option<String>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.read_conn()?;
         let mut stmt = conn.prepare("SELECT last_sync FROM sync_cursor WHERE project = ?1")?;
         let mut rows = stmt.query(params![project])?;
 
@@ -203,7 +919,7 @@ ON CONFLICT(issue_key) DO UPDATE SET
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn set_sync_cursor(&self, project: &str, last_sync: &str) -> Result<(), rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.write_conn()?;
         conn.execute(
             "
 INSERT INTO sync_cursor(project, last_sync)
@@ -221,7 +937,7 @@ ON CONFLICT(project) DO UPDATE SET
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn clear_sync_cursor(&self, project: &str) -> Result<(), rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.write_conn()?;
         conn.execute(
             "DELETE FROM sync_cursor WHERE project = ?1",
             params![project],
@@ -234,7 +950,7 @@ ON CONFLICT(project) DO UPDATE SET
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn cached_issue_count(&self, project_prefix: &str) -> Result<usize, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.read_conn()?;
         let pattern = format!("{}-%", project_prefix);
         let count: usize = conn.query_row(
             "SELECT COUNT(*) FROM issues WHERE issue_key LIKE ?1",
@@ -244,21 +960,14 @@ ON CONFLICT(project) DO UPDATE SET
         Ok(count)
     }
 
-    /// Returns stored markdown size in bytes for one issue.
+    /// Returns stored markdown size in bytes for one issue, decompressed
+    /// (this is what callers like FUSE `getattr` report as file size, so it
+    /// must reflect the logical content, not the on-disk compressed blob).
     ///
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn issue_markdown_len(&self, issue_key: &str) -> Result<Option<u64>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
-        let mut stmt = conn.prepare("SELECT length(markdown) FROM issues WHERE issue_key = ?1")?;
-        let mut rows = stmt.query(params![issue_key])?;
-
-        if let Some(row) = rows.next()? {
-            let len: i64 = row.get(0)?;
-            return Ok(Some(len.max(0) as u64));
-        }
-
-        Ok(None)
+        self.peek_markdown_len(issue_key)
     }
 
     /// Lists persisted ticket index rows.
@@ -269,7 +978,7 @@ ON CONFLICT(project) DO UPDATE SET
         &self,
         projects: &[String],
     ) -> Result<Vec<TicketIndexRow>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.read_conn()?;
         let mut stmt = conn.prepare(
             "SELECT issue_key, project, updated_at, path FROM ticket_index ORDER BY issue_key ASC",
         )?;
@@ -297,7 +1006,7 @@ ON CONFLICT(project) DO UPDATE SET
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn list_project_issue_refs(&self, project: &str) -> Result<Vec<IssueRef>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.read_conn()?;
         let mut stmt = conn.prepare(
             "SELECT issue_key, updated_at FROM ticket_index WHERE project = ?1 ORDER BY issue_key ASC",
         )?;
@@ -314,6 +1023,51 @@ ON CONFLICT(project) DO UPDATE SET
         Ok(out)
     }
 
+    /// Paginated range scan over the ticket index, modeled on a KV range
+    /// read: `issue_key` in `[start_key, end_key)`, optionally narrowed to
+    /// `project`, capped at `limit` rows. Callers page by passing the last
+    /// `issue_key` seen back in as the next call's `start_key` (or `end_key`
+    /// for `reverse`), instead of materializing the whole index like
+    /// [`Self::list_ticket_index`]/[`Self::list_project_issue_refs`] do.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn scan_ticket_index(
+        &self,
+        project: Option<&str>,
+        start_key: Option<&str>,
+        end_key: Option<&str>,
+        limit: usize,
+        reverse: bool,
+    ) -> Result<Vec<TicketIndexRow>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let order = if reverse { "DESC" } else { "ASC" };
+        let sql = format!(
+            "
+SELECT issue_key, project, updated_at, path FROM ticket_index
+WHERE (?1 IS NULL OR project = ?1)
+  AND (?2 IS NULL OR issue_key >= ?2)
+  AND (?3 IS NULL OR issue_key < ?3)
+ORDER BY issue_key {order}
+LIMIT ?4
+"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let mut rows = stmt.query(params![project, start_key, end_key, limit as i64])?;
+        let mut out = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            out.push(TicketIndexRow {
+                id: row.get(0)?,
+                project: row.get(1)?,
+                updated_at: row.get(2)?,
+                path: row.get(3)?,
+            });
+        }
+
+        Ok(out)
+    }
+
     /// Upserts markdown and jsonl comment sidecars for one issue.
     ///
     /// # Errors
@@ -326,19 +1080,31 @@ ON CONFLICT(project) DO UPDATE SET
         updated: Option<&str>,
     ) -> Result<(), rusqlite::Error> {
         let now = unix_epoch_seconds_string();
-        let conn = lock_conn_or_recover(&self.conn);
+        let ts = unix_epoch_seconds();
+        // Both blobs in a row share one `codec` column, so compress them
+        // together into a single tag instead of risking two codecs
+        // disagreeing for the same row.
+        let (codec, md, jsonl) = compress_sidecar_pair(self.compression_level, comments_md, comments_jsonl, &self.metrics)
+            .map_err(persistence_err_to_sqlite)?;
+        let conn = self.write_conn()?;
         conn.execute(
             "
-INSERT INTO issue_sidecars(issue_key, comments_md, comments_jsonl, updated, cached_at)
-VALUES (?1, ?2, ?3, ?4, ?5)
+INSERT INTO issue_sidecars(issue_key, comments_md, comments_jsonl, updated, cached_at, codec)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6)
 ON CONFLICT(issue_key) DO UPDATE SET
   comments_md = excluded.comments_md,
   comments_jsonl = excluded.comments_jsonl,
   updated = excluded.updated,
-  cached_at = excluded.cached_at
+  cached_at = excluded.cached_at,
+  codec = excluded.codec
 ",
-            params![issue_key, comments_md, comments_jsonl, updated, now],
+            params![issue_key, md, jsonl, updated, now, codec],
         )?;
+        self.record_change(&conn, issue_key, "comments_md", comments_md, ts)?;
+        self.record_change(&conn, issue_key, "comments_jsonl", comments_jsonl, ts)?;
+        if let Some(updated) = updated {
+            self.record_change(&conn, issue_key, "updated", updated.as_bytes(), ts)?;
+        }
         Ok(())
     }
 
@@ -351,22 +1117,26 @@ ON CONFLICT(issue_key) DO UPDATE SET
         sidecars: &[PersistentSidecarRow],
     ) -> Result<usize, rusqlite::Error> {
         let now = unix_epoch_seconds_string();
-        let mut conn = lock_conn_or_recover(&self.conn);
+        let mut conn = self.write_conn()?;
         let tx = conn.transaction()?;
 
         let mut count = 0;
         for (issue_key, comments_md, comments_jsonl, updated) in sidecars {
+            let (codec, md, jsonl) =
+                compress_sidecar_pair(self.compression_level, comments_md, comments_jsonl, &self.metrics)
+                    .map_err(persistence_err_to_sqlite)?;
             tx.execute(
                 "
-INSERT INTO issue_sidecars(issue_key, comments_md, comments_jsonl, updated, cached_at)
-VALUES (?1, ?2, ?3, ?4, ?5)
+INSERT INTO issue_sidecars(issue_key, comments_md, comments_jsonl, updated, cached_at, codec)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6)
 ON CONFLICT(issue_key) DO UPDATE SET
   comments_md = excluded.comments_md,
   comments_jsonl = excluded.comments_jsonl,
   updated = excluded.updated,
-  cached_at = excluded.cached_at
+  cached_at = excluded.cached_at,
+  codec = excluded.codec
 ",
-                params![issue_key, comments_md, comments_jsonl, updated, now],
+                params![issue_key, md, jsonl, updated, now, codec],
             )?;
             count += 1;
         }
@@ -383,13 +1153,16 @@ ON CONFLICT(issue_key) DO UPDATE SET
         &self,
         issue_key: &str,
     ) -> Result<Option<Vec<u8>>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.read_conn()?;
         let mut stmt =
-            conn.prepare("SELECT comments_md FROM issue_sidecars WHERE issue_key = ?1")?;
+            conn.prepare("SELECT comments_md, codec FROM issue_sidecars WHERE issue_key = ?1")?;
         let mut rows = stmt.query(params![issue_key])?;
         if let Some(row) = rows.next()? {
-            let bytes: Vec<u8> = row.get(0)?;
-            return Ok(Some(bytes));
+            let raw: Vec<u8> = row.get(0)?;
+            let codec: u8 = row.get(1)?;
+            return Ok(Some(
+                backend::decompress(codec, &raw).map_err(persistence_err_to_sqlite)?,
+            ));
         }
         Ok(None)
     }
@@ -402,36 +1175,31 @@ ON CONFLICT(issue_key) DO UPDATE SET
         &self,
         issue_key: &str,
     ) -> Result<Option<Vec<u8>>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
+        let conn = self.read_conn()?;
         let mut stmt =
-            conn.prepare("SELECT comments_jsonl FROM issue_sidecars WHERE issue_key = ?1")?;
+            conn.prepare("SELECT comments_jsonl, codec FROM issue_sidecars WHERE issue_key = ?1")?;
         let mut rows = stmt.query(params![issue_key])?;
         if let Some(row) = rows.next()? {
-            let bytes: Vec<u8> = row.get(0)?;
-            return Ok(Some(bytes));
+            let raw: Vec<u8> = row.get(0)?;
+            let codec: u8 = row.get(1)?;
+            return Ok(Some(
+                backend::decompress(codec, &raw).map_err(persistence_err_to_sqlite)?,
+            ));
         }
         Ok(None)
     }
 
-    /// Returns markdown sidecar size in bytes for one issue.
+    /// Returns markdown sidecar size in bytes for one issue, decompressed.
     ///
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
     pub fn issue_comments_md_len(&self, issue_key: &str) -> Result<Option<u64>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
-        let mut stmt =
-            conn.prepare("SELECT length(comments_md) FROM issue_sidecars WHERE issue_key = ?1")?;
-        let mut rows = stmt.query(params![issue_key])?;
-
-        if let Some(row) = rows.next()? {
-            let len: i64 = row.get(0)?;
-            return Ok(Some(len.max(0) as u64));
-        }
-
-        Ok(None)
+        Ok(self
+            .get_issue_comments_md(issue_key)?
+            .map(|bytes| bytes.len() as u64))
     }
 
-    /// Returns JSONL sidecar size in bytes for one issue.
+    /// Returns JSONL sidecar size in bytes for one issue, decompressed.
     ///
     /// # Errors
     /// Returns [`rusqlite::Error`] when SQL execution fails.
@@ -439,40 +1207,475 @@ ON CONFLICT(issue_key) DO UPDATE SET
         &self,
         issue_key: &str,
     ) -> Result<Option<u64>, rusqlite::Error> {
-        let conn = lock_conn_or_recover(&self.conn);
-        let mut stmt =
-            conn.prepare("SELECT length(comments_jsonl) FROM issue_sidecars WHERE issue_key = ?1")?;
-        let mut rows = stmt.query(params![issue_key])?;
-
-        if let Some(row) = rows.next()? {
-            let len: i64 = row.get(0)?;
-            return Ok(Some(len.max(0) as u64));
-        }
-
-        Ok(None)
+        Ok(self
+            .get_issue_comments_jsonl(issue_key)?
+            .map(|bytes| bytes.len() as u64))
     }
 }
 
-fn lock_conn_or_recover(conn: &Mutex<Connection>) -> MutexGuard<'_, Connection> {
-    match conn.lock() {
-        Ok(guard) => guard,
-        Err(poisoned) => {
-            logging::warn("recovering poisoned mutex: persistent cache connection");
-            poisoned.into_inner()
-        }
+impl PersistentCache {
+    /// Reads a generic opaque blob by key (used for checkpoint/resume state).
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn get_blob(&self, key: &str) -> Result<Option<Vec<u8>>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare("SELECT value FROM blobs WHERE key = ?1")?;
+        let mut rows = stmt.query(params![key])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    /// Writes a generic opaque blob by key.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn set_blob(&self, key: &str, value: &[u8]) -> Result<(), rusqlite::Error> {
+        let now = unix_epoch_seconds_string();
+        let conn = self.write_conn()?;
+        conn.execute(
+            "
+INSERT INTO blobs(key, value, updated_at)
+VALUES (?1, ?2, ?3)
+ON CONFLICT(key) DO UPDATE SET
+  value = excluded.value,
+  updated_at = excluded.updated_at
+",
+            params![key, value, now],
+        )?;
+        Ok(())
+    }
+
+    /// Removes a generic opaque blob by key, if present.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn clear_blob(&self, key: &str) -> Result<(), rusqlite::Error> {
+        let conn = self.write_conn()?;
+        conn.execute("DELETE FROM blobs WHERE key = ?1", params![key])?;
+        Ok(())
     }
 }
 
-fn unix_epoch_seconds_string() -> String {
+impl PersistentCache {
+    /// Lists all saved virtual query directories, as (name, jql) pairs.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn list_queries(&self) -> Result<Vec<(String, String)>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare("SELECT name, jql FROM queries ORDER BY name ASC")?;
+        let mut rows = stmt.query([])?;
+        let mut out = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            out.push((row.get(0)?, row.get(1)?));
+        }
+
+        Ok(out)
+    }
+
+    /// Saves a virtual query directory so it survives a remount.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn upsert_query(&self, name: &str, jql: &str) -> Result<(), rusqlite::Error> {
+        let now = unix_epoch_seconds_string();
+        let conn = self.write_conn()?;
+        conn.execute(
+            "
+INSERT INTO queries(name, jql, created_at)
+VALUES (?1, ?2, ?3)
+ON CONFLICT(name) DO UPDATE SET
+  jql = excluded.jql,
+  created_at = excluded.created_at
+",
+            params![name, jql, now],
+        )?;
+        Ok(())
+    }
+
+    /// Drops a saved virtual query directory.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn remove_query(&self, name: &str) -> Result<(), rusqlite::Error> {
+        let conn = self.write_conn()?;
+        conn.execute("DELETE FROM queries WHERE name = ?1", params![name])?;
+        Ok(())
+    }
+}
+
+impl PersistentCache {
+    /// Retains a prior rendered snapshot of an issue, then trims the ring
+    /// back down to `max_versions` (oldest first).
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn append_issue_history(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        max_versions: usize,
+    ) -> Result<String, rusqlite::Error> {
+        let conn = self.write_conn()?;
+        let saved_at = iso8601_version_id(&conn)?;
+        conn.execute(
+            "INSERT INTO issue_history(issue_key, version_id, markdown, saved_at) VALUES (?1, ?2, ?3, ?4)",
+            params![issue_key, saved_at, markdown, saved_at],
+        )?;
+        // Disambiguate versions saved within the same second by suffixing the
+        // row id, so `version_id` stays collision-free even under rapid scrub
+        // churn instead of silently overwriting same-second history.
+        let row_id = conn.last_insert_rowid();
+        let version_id = format!("{}-{}", saved_at, row_id);
+        conn.execute(
+            "UPDATE issue_history SET version_id = ?1 WHERE id = ?2",
+            params![version_id, row_id],
+        )?;
+        conn.execute(
+            "
+DELETE FROM issue_history
+WHERE issue_key = ?1
+  AND id NOT IN (
+    SELECT id FROM issue_history WHERE issue_key = ?1 ORDER BY id DESC LIMIT ?2
+  )
+",
+            params![issue_key, max_versions as i64],
+        )?;
+        Ok(version_id)
+    }
+
+    /// Lists the retained version ids for an issue, oldest first.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn list_issue_history_versions(
+        &self,
+        issue_key: &str,
+    ) -> Result<Vec<String>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT version_id FROM issue_history WHERE issue_key = ?1 ORDER BY id ASC",
+        )?;
+        let mut rows = stmt.query(params![issue_key])?;
+        let mut out = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            out.push(row.get(0)?);
+        }
+
+        Ok(out)
+    }
+
+    /// Reads one retained snapshot's markdown bytes.
+    ///
+    /// # Errors
+    /// Returns [`rusqlite::Error`] when SQL execution fails.
+    pub fn get_issue_history_version(
+        &self,
+        issue_key: &str,
+        version_id: &str,
+    ) -> Result<Option<Vec<u8>>, rusqlite::Error> {
+        let conn = self.read_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT markdown FROM issue_history WHERE issue_key = ?1 AND version_id = ?2",
+        )?;
+        let mut rows = stmt.query(params![issue_key, version_id])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+}
+
+impl super::backend::PersistenceBackend for PersistentCache {
+    fn get_issue(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<PersistentIssue>, super::backend::PersistenceError> {
+        Ok(self.get_issue(issue_key)?)
+    }
+
+    fn upsert_issue(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        updated: Option<&str>,
+    ) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.upsert_issue(issue_key, markdown, updated)?)
+    }
+
+    fn remove_issue(&self, issue_key: &str) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.remove_issue(issue_key)?)
+    }
+
+    fn upsert_issues_batch(
+        &self,
+        issues: &[PersistentIssueRow],
+    ) -> Result<usize, super::backend::PersistenceError> {
+        Ok(self.upsert_issues_batch(issues)?)
+    }
+
+    fn get_sync_cursor(
+        &self,
+        project: &str,
+    ) -> Result<Option<String>, super::backend::PersistenceError> {
+        Ok(self.get_sync_cursor(project)?)
+    }
+
+    fn set_sync_cursor(
+        &self,
+        project: &str,
+        last_sync: &str,
+    ) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.set_sync_cursor(project, last_sync)?)
+    }
+
+    fn clear_sync_cursor(&self, project: &str) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.clear_sync_cursor(project)?)
+    }
+
+    fn cached_issue_count(
+        &self,
+        project_prefix: &str,
+    ) -> Result<usize, super::backend::PersistenceError> {
+        Ok(self.cached_issue_count(project_prefix)?)
+    }
+
+    fn issue_markdown_len(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<u64>, super::backend::PersistenceError> {
+        Ok(self.issue_markdown_len(issue_key)?)
+    }
+
+    fn list_ticket_index(
+        &self,
+        projects: &[String],
+    ) -> Result<Vec<TicketIndexRow>, super::backend::PersistenceError> {
+        Ok(self.list_ticket_index(projects)?)
+    }
+
+    fn list_project_issue_refs(
+        &self,
+        project: &str,
+    ) -> Result<Vec<IssueRef>, super::backend::PersistenceError> {
+        Ok(self.list_project_issue_refs(project)?)
+    }
+
+    fn scan_ticket_index(
+        &self,
+        project: Option<&str>,
+        start_key: Option<&str>,
+        end_key: Option<&str>,
+        limit: usize,
+        reverse: bool,
+    ) -> Result<Vec<TicketIndexRow>, super::backend::PersistenceError> {
+        Ok(self.scan_ticket_index(project, start_key, end_key, limit, reverse)?)
+    }
+
+    fn upsert_issue_sidecars(
+        &self,
+        issue_key: &str,
+        comments_md: &[u8],
+        comments_jsonl: &[u8],
+        updated: Option<&str>,
+    ) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.upsert_issue_sidecars(issue_key, comments_md, comments_jsonl, updated)?)
+    }
+
+    fn upsert_issue_sidecars_batch(
+        &self,
+        sidecars: &[PersistentSidecarRow],
+    ) -> Result<usize, super::backend::PersistenceError> {
+        Ok(self.upsert_issue_sidecars_batch(sidecars)?)
+    }
+
+    fn get_issue_comments_md(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<Vec<u8>>, super::backend::PersistenceError> {
+        Ok(self.get_issue_comments_md(issue_key)?)
+    }
+
+    fn get_issue_comments_jsonl(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<Vec<u8>>, super::backend::PersistenceError> {
+        Ok(self.get_issue_comments_jsonl(issue_key)?)
+    }
+
+    fn issue_comments_md_len(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<u64>, super::backend::PersistenceError> {
+        Ok(self.issue_comments_md_len(issue_key)?)
+    }
+
+    fn issue_comments_jsonl_len(
+        &self,
+        issue_key: &str,
+    ) -> Result<Option<u64>, super::backend::PersistenceError> {
+        Ok(self.issue_comments_jsonl_len(issue_key)?)
+    }
+
+    fn get_blob(&self, key: &str) -> Result<Option<Vec<u8>>, super::backend::PersistenceError> {
+        Ok(self.get_blob(key)?)
+    }
+
+    fn set_blob(&self, key: &str, value: &[u8]) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.set_blob(key, value)?)
+    }
+
+    fn clear_blob(&self, key: &str) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.clear_blob(key)?)
+    }
+
+    fn list_queries(&self) -> Result<Vec<(String, String)>, super::backend::PersistenceError> {
+        Ok(self.list_queries()?)
+    }
+
+    fn upsert_query(&self, name: &str, jql: &str) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.upsert_query(name, jql)?)
+    }
+
+    fn remove_query(&self, name: &str) -> Result<(), super::backend::PersistenceError> {
+        Ok(self.remove_query(name)?)
+    }
+
+    fn append_issue_history(
+        &self,
+        issue_key: &str,
+        markdown: &[u8],
+        max_versions: usize,
+    ) -> Result<String, super::backend::PersistenceError> {
+        Ok(self.append_issue_history(issue_key, markdown, max_versions)?)
+    }
+
+    fn list_issue_history_versions(
+        &self,
+        issue_key: &str,
+    ) -> Result<Vec<String>, super::backend::PersistenceError> {
+        Ok(self.list_issue_history_versions(issue_key)?)
+    }
+
+    fn get_issue_history_version(
+        &self,
+        issue_key: &str,
+        version_id: &str,
+    ) -> Result<Option<Vec<u8>>, super::backend::PersistenceError> {
+        Ok(self.get_issue_history_version(issue_key, version_id)?)
+    }
+}
+
+#[cfg(test)]
+fn metrics() -> Arc<Metrics> {
+    Arc::new(Metrics::new())
+}
+
+#[cfg(test)]
+mod backend_contract_tests {
+    use super::*;
+
+    #[test]
+    fn sqlite_backend_satisfies_issue_roundtrip_contract() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+        super::super::backend::contract::assert_issue_roundtrip(&db);
+    }
+
+    #[test]
+    fn sqlite_backend_satisfies_sync_cursor_contract() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+        super::super::backend::contract::assert_sync_cursor_roundtrip(&db);
+    }
+}
+
+/// `rusqlite::Error` has no "arbitrary boxed error" variant of its own, so
+/// borrow `ToSqlConversionFailure` to carry a zstd (de)compression failure
+/// through methods whose signature predates compression and still returns
+/// `rusqlite::Error`.
+fn persistence_err_to_sqlite(err: backend::PersistenceError) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(err)
+}
+
+/// Same escape hatch as [`persistence_err_to_sqlite`], for the one other
+/// error type this module's `rusqlite::Error`-returning methods need to
+/// carry: a failure to check out from or build either pool.
+fn pool_err_to_sqlite(err: r2d2::Error) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(Box::new(err))
+}
+
+/// SQLite's `:memory:` path gives each connection its own private, empty
+/// database, which would defeat a read pool entirely — every checkout would
+/// see a different database than the writer. Rewriting it to a named
+/// shared-cache URI makes every connection opened against it (writer and
+/// every pooled reader) see the same in-memory database instead. Each call
+/// picks a fresh name, so two caches opened on `:memory:` stay separate. Real
+/// on-disk paths pass through unchanged.
+fn shared_cache_uri(path: &Path) -> String {
+    if path == Path::new(":memory:") {
+        format!("file:fs-jira-{}?mode=memory&cache=shared", Uuid::new_v4())
+    } else {
+        path.to_string_lossy().into_owned()
+    }
+}
+
+/// Compresses a sidecar row's two blobs under one shared codec tag, which
+/// holds because `backend::compress` always tags its output `CODEC_ZSTD`. If
+/// either blob fails to compress, the error is returned and nothing is stored.
+fn compress_sidecar_pair(
+    level: i32,
+    comments_md: &[u8],
+    comments_jsonl: &[u8],
+    metrics: &Metrics,
+) -> Result<(u8, Vec<u8>, Vec<u8>), backend::PersistenceError> {
+    let (md_codec, md) = backend::compress(level, comments_md, metrics)?;
+    let (jsonl_codec, jsonl) = backend::compress(level, comments_jsonl, metrics)?;
+    debug_assert_eq!(md_codec, jsonl_codec, "compress() always returns CODEC_ZSTD");
+    Ok((md_codec, md, jsonl))
+}
+
+/// Formats "now" as an ISO8601 UTC timestamp via SQLite's `strftime`, since
+/// this crate has no chrono dependency to do it in pure Rust.
+fn iso8601_version_id(conn: &Connection) -> Result<String, rusqlite::Error> {
+    conn.query_row("SELECT strftime('%Y-%m-%dT%H:%M:%SZ', 'now')", [], |row| {
+        row.get(0)
+    })
+}
+
+fn unix_epoch_seconds() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_secs().to_string())
+        .map(|duration| duration.as_secs() as i64)
         .unwrap_or_else(|_| {
             logging::warn("system clock before unix epoch; using fallback timestamp 0");
-            "0".to_string()
+            0
         })
 }
 
+fn unix_epoch_seconds_string() -> String {
+    unix_epoch_seconds().to_string()
+}
+
 fn upsert_ticket_index(
     conn: &Connection,
     issue_key: &str,
@@ -509,7 +1712,15 @@ mod tests {
 
     #[test]
     fn persists_and_reads_issue() {
-        let db = PersistentCache::new(Path::new(":memory:")).expect("db open");
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
         db.upsert_issue("PROJ-1", b"hello", Some("u1"))
             .expect("upsert");
 
@@ -520,7 +1731,15 @@ mod tests {
 
     #[test]
     fn sync_cursor_roundtrip() {
-        let db = PersistentCache::new(Path::new(":memory:")).expect("db open");
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
 
         assert!(db.get_sync_cursor("PROJ").expect("get").is_none());
 
@@ -539,7 +1758,15 @@ mod tests {
 
     #[test]
     fn batch_upsert_issues() {
-        let db = PersistentCache::new(Path::new(":memory:")).expect("db open");
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
 
         let issues = vec![
             (
@@ -566,7 +1793,15 @@ mod tests {
 
     #[test]
     fn keeps_ticket_index_in_sync() {
-        let db = PersistentCache::new(Path::new(":memory:")).expect("db open");
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
         db.upsert_issue("ST-10", b"v1", Some("2026-02-22T10:00:00.000+0000"))
             .expect("upsert");
 
@@ -586,9 +1821,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scan_ticket_index_pages_by_last_seen_key() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        for key in ["ST-1", "ST-2", "ST-3", "OTHER-1"] {
+            db.upsert_issue(key, b"v", Some("u")).expect("upsert");
+        }
+
+        let page1 = db
+            .scan_ticket_index(Some("ST"), None, None, 2, false)
+            .expect("scan");
+        assert_eq!(
+            page1.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+            vec!["ST-1", "ST-2"]
+        );
+
+        let page2 = db
+            .scan_ticket_index(Some("ST"), Some(&page1.last().unwrap().id), None, 2, false)
+            .expect("scan");
+        assert_eq!(
+            page2.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+            vec!["ST-2", "ST-3"]
+        );
+
+        let reversed = db
+            .scan_ticket_index(Some("ST"), None, None, 2, true)
+            .expect("scan");
+        assert_eq!(
+            reversed.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+            vec!["ST-3", "ST-2"]
+        );
+    }
+
+    #[test]
+    fn blob_roundtrip() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+        assert!(db.get_blob("sync_checkpoint").expect("get").is_none());
+
+        db.set_blob("sync_checkpoint", b"v1").expect("set");
+        assert_eq!(
+            db.get_blob("sync_checkpoint").expect("get").expect("present"),
+            b"v1"
+        );
+
+        db.set_blob("sync_checkpoint", b"v2").expect("overwrite");
+        assert_eq!(
+            db.get_blob("sync_checkpoint").expect("get").expect("present"),
+            b"v2"
+        );
+
+        db.clear_blob("sync_checkpoint").expect("clear");
+        assert!(db.get_blob("sync_checkpoint").expect("get").is_none());
+    }
+
+    #[test]
+    fn query_directories_roundtrip() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+        assert!(db.list_queries().expect("list").is_empty());
+
+        db.upsert_query("unresolved%20bugs", "project = PROJ AND status != Done")
+            .expect("upsert");
+
+        let queries = db.list_queries().expect("list");
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].0, "unresolved%20bugs");
+        assert_eq!(queries[0].1, "project = PROJ AND status != Done");
+
+        db.remove_query("unresolved%20bugs").expect("remove");
+        assert!(db.list_queries().expect("list").is_empty());
+    }
+
     #[test]
     fn persists_sidecars() {
-        let db = PersistentCache::new(Path::new(":memory:")).expect("db open");
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
         db.upsert_issue_sidecars("DATA-1", b"md", b"jsonl", Some("u1"))
             .expect("upsert sidecars");
 
@@ -609,4 +1947,315 @@ mod tests {
             2
         );
     }
+
+    #[test]
+    fn issue_history_roundtrip_and_trim() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+        assert!(db
+            .list_issue_history_versions("PROJ-1")
+            .expect("list")
+            .is_empty());
+
+        let first = db
+            .append_issue_history("PROJ-1", b"v1", 2)
+            .expect("append v1");
+        db.append_issue_history("PROJ-1", b"v2", 2)
+            .expect("append v2");
+        let third = db
+            .append_issue_history("PROJ-1", b"v3", 2)
+            .expect("append v3");
+
+        let versions = db.list_issue_history_versions("PROJ-1").expect("list");
+        assert_eq!(versions.len(), 2, "ring should trim back to max_versions");
+        assert!(!versions.contains(&first), "oldest version should be trimmed");
+
+        let latest = db
+            .get_issue_history_version("PROJ-1", &third)
+            .expect("get")
+            .expect("present");
+        assert_eq!(latest, b"v3");
+    }
+
+    #[test]
+    fn legacy_uncompressed_rows_stay_readable() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        // Simulate a row written before the `codec` column existed: insert
+        // directly, bypassing `upsert_issue`'s compression, with the
+        // column's `DEFAULT 0` left in place.
+        {
+            let conn = db.write_conn().expect("conn");
+            conn.execute(
+                "INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count) VALUES ('OLD-1', 'raw markdown', 'u1', '0', 1)",
+                [],
+            )
+            .expect("insert legacy row");
+        }
+
+        let got = db.get_issue("OLD-1").expect("read").expect("present");
+        assert_eq!(got.markdown, b"raw markdown");
+    }
+
+    #[test]
+    fn compressed_markdown_roundtrips_with_large_repetitive_content() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        let markdown = "line of fairly repetitive markdown text\n".repeat(200);
+        db.upsert_issue("PROJ-1", markdown.as_bytes(), Some("u1"))
+            .expect("upsert");
+
+        let got = db.get_issue("PROJ-1").expect("read").expect("present");
+        assert_eq!(got.markdown, markdown.as_bytes());
+        assert_eq!(
+            db.issue_markdown_len("PROJ-1").expect("len").expect("present"),
+            markdown.len() as u64
+        );
+    }
+
+    #[test]
+    fn atomic_write_creates_and_stamps_version() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        let new_version = db
+            .atomic_write(
+                &[("PROJ-1".to_string(), None)],
+                &[AtomicMutation::UpsertIssue {
+                    issue_key: "PROJ-1".to_string(),
+                    markdown: b"hello".to_vec(),
+                    updated: Some("u1".to_string()),
+                }],
+            )
+            .expect("atomic write")
+            .expect("checks passed");
+        assert_eq!(new_version, 1);
+
+        let got = db.get_issue("PROJ-1").expect("read").expect("present");
+        assert_eq!(got.markdown, b"hello");
+        assert_eq!(got.version, 1);
+    }
+
+    #[test]
+    fn atomic_write_rejects_stale_expected_version() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        db.atomic_write(
+            &[("PROJ-1".to_string(), None)],
+            &[AtomicMutation::UpsertIssue {
+                issue_key: "PROJ-1".to_string(),
+                markdown: b"v1".to_vec(),
+                updated: Some("u1".to_string()),
+            }],
+        )
+        .expect("atomic write")
+        .expect("checks passed");
+
+        // A racing writer bumps the version to 2 behind this caller's back.
+        db.atomic_write(
+            &[("PROJ-1".to_string(), Some(1))],
+            &[AtomicMutation::UpsertIssue {
+                issue_key: "PROJ-1".to_string(),
+                markdown: b"v2".to_vec(),
+                updated: Some("u2".to_string()),
+            }],
+        )
+        .expect("atomic write")
+        .expect("checks passed");
+
+        // This caller still thinks the version is 1 and must be rejected.
+        let result = db
+            .atomic_write(
+                &[("PROJ-1".to_string(), Some(1))],
+                &[AtomicMutation::UpsertIssue {
+                    issue_key: "PROJ-1".to_string(),
+                    markdown: b"stale".to_vec(),
+                    updated: Some("stale".to_string()),
+                }],
+            )
+            .expect("atomic write");
+        assert!(result.is_none());
+
+        let got = db.get_issue("PROJ-1").expect("read").expect("present");
+        assert_eq!(got.markdown, b"v2", "rejected write must not have applied");
+        assert_eq!(got.version, 2);
+    }
+
+    #[test]
+    fn enforce_cache_budget_evicts_coldest_first() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        // Inserted directly (bypassing compression) so `length(markdown)` is
+        // a known, exact 10 bytes per row.
+        {
+            let conn = db.write_conn().expect("conn");
+            for (key, access_count) in [("PROJ-1", 5), ("PROJ-2", 1), ("PROJ-3", 5)] {
+                conn.execute(
+                    "INSERT INTO issues(issue_key, markdown, updated, cached_at, access_count) VALUES (?1, 'aaaaaaaaaa', 'u', '0', ?2)",
+                    params![key, access_count],
+                )
+                .expect("insert row");
+            }
+        }
+
+        let (evicted, reclaimed) = db.enforce_cache_budget(20).expect("enforce budget");
+        assert_eq!(evicted, 1);
+        assert_eq!(reclaimed, 10);
+
+        assert!(db.get_issue("PROJ-2").expect("read").is_none());
+        assert!(db.get_issue("PROJ-1").expect("read").is_some());
+        assert!(db.get_issue("PROJ-3").expect("read").is_some());
+    }
+
+    #[test]
+    fn pending_write_queue_roundtrip_and_backoff() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        let id = db
+            .enqueue_write("PROJ-1", "set_status", b"Done")
+            .expect("enqueue");
+
+        let ready = db.dequeue_ready("9999999999", 10).expect("dequeue");
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].id, id);
+        assert_eq!(ready[0].issue_key, "PROJ-1");
+        assert_eq!(ready[0].kind, "set_status");
+        assert_eq!(ready[0].payload, b"Done");
+        assert_eq!(ready[0].attempts, 0);
+
+        db.defer_write(id, 3600).expect("defer");
+        assert!(db.dequeue_ready("0", 10).expect("dequeue").is_empty());
+
+        let still_queued = db.dequeue_ready("9999999999", 10).expect("dequeue");
+        assert_eq!(still_queued.len(), 1);
+        assert_eq!(still_queued[0].attempts, 1);
+
+        db.ack_write(id).expect("ack");
+        assert!(db.dequeue_ready("9999999999", 10).expect("dequeue").is_empty());
+    }
+
+    #[test]
+    fn apply_changes_merges_into_a_second_cache() {
+        let laptop = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+        let desktop = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        laptop
+            .upsert_issue("PROJ-1", b"from laptop", Some("u1"))
+            .expect("upsert");
+
+        let changes = laptop.export_changes(None).expect("export");
+        assert!(!changes.is_empty());
+
+        desktop.apply_changes(&changes).expect("apply");
+        let got = desktop.get_issue("PROJ-1").expect("read").expect("present");
+        assert_eq!(got.markdown, b"from laptop");
+        assert_eq!(got.updated.as_deref(), Some("u1"));
+
+        // Exporting again from the high-water mark yields nothing new.
+        let high_water = changes.last().unwrap().seq;
+        assert!(laptop
+            .export_changes(Some(high_water))
+            .expect("export")
+            .is_empty());
+    }
+
+    #[test]
+    fn apply_changes_rejects_stale_writes_last_writer_wins() {
+        let db = PersistentCache::new(
+            Path::new(":memory:"),
+            backend::DEFAULT_COMPRESSION_LEVEL,
+            backend::DEFAULT_MIN_READ_CONN,
+            backend::DEFAULT_MAX_READ_CONN,
+            None,
+            metrics(),
+        )
+        .expect("db open");
+
+        db.upsert_issue("PROJ-1", b"current", Some("u1"))
+            .expect("upsert");
+        let current_ts = db.export_changes(None).expect("export")[0].ts;
+
+        let stale = Change {
+            seq: 0,
+            site_id: "other-site".to_string(),
+            issue_key: "PROJ-1".to_string(),
+            column: "markdown".to_string(),
+            value: b"stale".to_vec(),
+            ts: current_ts - 100,
+        };
+        db.apply_changes(&[stale]).expect("apply");
+
+        let got = db.get_issue("PROJ-1").expect("read").expect("present");
+        assert_eq!(got.markdown, b"current", "stale change must not overwrite newer local write");
+    }
 }
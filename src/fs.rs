@@ -1,22 +1,58 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
-use std::io;
+use std::io::{self, BufRead, Write as _};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::sync::MutexGuard;
-use std::time::{Duration, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use fuser::{
     Errno, FileAttr, FileHandle, FileType, Filesystem, FopenFlags, Generation, INodeNo,
-    OpenAccMode, OpenFlags, ReplyAttr, ReplyData, ReplyDirectory, ReplyEntry, ReplyOpen,
-    ReplyWrite, Request, TimeOrNow,
+    OpenAccMode, OpenFlags, ReplyAttr, ReplyData, ReplyDirectory, ReplyEmpty, ReplyEntry,
+    ReplyOpen, ReplyWrite, Request, TimeOrNow,
 };
 
 use crate::cache::InMemoryCache;
 use crate::jira::JiraClient;
 use crate::logging;
+use crate::periodic_sync::{spawn_periodic_sync_worker, PeriodicSyncStatus};
+use crate::render::{render_issue_comments_markdown, render_issue_markdown};
+use crate::scrub::{spawn_scrub_worker, ScrubStatus};
 use crate::sync_state::SyncState;
-use crate::warmup::sync_issues;
+use crate::warmup::{parse_jira_timestamp, sync_issues_resumable};
+use crate::workers::{WorkerCommand, WorkerId, WorkerManager};
+use crate::writeback::{self, parse_front_matter};
+
+const DEFAULT_SCRUB_TRANQUILITY: u64 = 2;
+const DEFAULT_SYNC_TRANQUILITY: u64 = 1;
+const QUERY_JQL_FILE_NAME: &str = "query.jql";
+
+/// How often `release` re-checks `SyncState` while waiting out an
+/// in-progress sync before writing a staged issue edit back to Jira; see
+/// [`JiraFuseFs::wait_for_sync_idle`].
+const RELEASE_SYNC_WAIT_POLL: Duration = Duration::from_millis(100);
+
+/// Upper bound on how long `release` blocks waiting for a sync to finish
+/// before giving up and returning `EAGAIN` without a guaranteed retry; see
+/// [`JiraFuseFs::wait_for_sync_idle`].
+const RELEASE_SYNC_WAIT_MAX: Duration = Duration::from_secs(10);
+
+trait MutexExt<T> {
+    fn lock_or_recover(&self, name: &'static str) -> std::sync::MutexGuard<'_, T>;
+}
+
+impl<T> MutexExt<T> for std::sync::Mutex<T> {
+    fn lock_or_recover(&self, name: &'static str) -> std::sync::MutexGuard<'_, T> {
+        match self.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                logging::warn(format!("recovering poisoned mutex: {}", name));
+                poisoned.into_inner()
+            }
+        }
+    }
+}
 
 const TTL: Duration = Duration::from_secs(1);
 
@@ -29,12 +65,25 @@ const INO_FULL_REFRESH: INodeNo = INodeNo(0x1004);
 const INO_PROJECTS: INodeNo = INodeNo(0x2000);
 const INO_TICKETS: INodeNo = INodeNo(0x3000);
 const INO_TICKETS_INDEX: INodeNo = INodeNo(0x3001);
-
-#[derive(Debug, Clone, Copy)]
+const INO_WORKERS: INodeNo = INodeNo(0x1006);
+const INO_WORKERS_INDEX: INodeNo = INodeNo(0x1007);
+const INO_RESUME_STATE: INodeNo = INodeNo(0x1008);
+const INO_SCRUB_TRANQUILITY: INodeNo = INodeNo(0x1009);
+const INO_SCRUB_STATUS: INodeNo = INodeNo(0x100a);
+const INO_REPAIR: INodeNo = INodeNo(0x100b);
+const INO_REPAIR_REPORT: INodeNo = INodeNo(0x100c);
+const INO_SYNC_TRANQUILITY: INodeNo = INodeNo(0x100d);
+const INO_SYNC_STATUS: INodeNo = INodeNo(0x100e);
+const INO_RECONCILE: INodeNo = INodeNo(0x100f);
+const INO_TOMBSTONES: INodeNo = INodeNo(0x1010);
+const INO_QUERIES: INodeNo = INodeNo(0x6000);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum IssueFileKind {
     Main,
     CommentsMarkdown,
     CommentsJsonl,
+    NewComment,
 }
 
 #[derive(Debug, Clone)]
@@ -44,9 +93,18 @@ enum Node {
     Projects,
     Project { name: String },
     Issue { key: String, kind: IssueFileKind },
+    IssueHistory { key: String },
+    IssueVersion { key: String, version_id: String },
     Tickets,
     TicketsIndex,
     SyncMetaFile,
+    Workers,
+    WorkersIndex,
+    WorkerDir { id: WorkerId },
+    WorkerControl { id: WorkerId, command: WorkerCommand },
+    Queries,
+    Query { name: String },
+    QueryJql { name: String },
 }
 
 #[derive(Debug, Default)]
@@ -54,6 +112,179 @@ struct FsState {
     nodes: HashMap<INodeNo, Node>,
 }
 
+/// Stable identity of an allocatable inode, independent of the inode number
+/// itself. This is what gets hashed for lookup and journaled for replay.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Project(String),
+    Issue(String, String, IssueFileKind),
+    IssueHistory(String),
+    IssueVersion(String, String),
+    Query(String),
+    QueryJql(String),
+    WorkerDir(WorkerId),
+    WorkerControl(WorkerId, WorkerCommand),
+}
+
+impl NodeKey {
+    fn issue_file_kind_tag(kind: IssueFileKind) -> &'static str {
+        match kind {
+            IssueFileKind::Main => "main",
+            IssueFileKind::CommentsMarkdown => "comments_md",
+            IssueFileKind::CommentsJsonl => "comments_jsonl",
+            IssueFileKind::NewComment => "new_comment",
+        }
+    }
+
+    fn issue_file_kind_from_tag(tag: &str) -> Option<IssueFileKind> {
+        match tag {
+            "main" => Some(IssueFileKind::Main),
+            "comments_md" => Some(IssueFileKind::CommentsMarkdown),
+            "comments_jsonl" => Some(IssueFileKind::CommentsJsonl),
+            "new_comment" => Some(IssueFileKind::NewComment),
+            _ => None,
+        }
+    }
+
+    fn to_journal_value(&self) -> serde_json::Value {
+        match self {
+            NodeKey::Project(name) => serde_json::json!({"kind": "project", "name": name}),
+            NodeKey::Issue(project, key, kind) => serde_json::json!({
+                "kind": "issue",
+                "project": project,
+                "key": key,
+                "file_kind": Self::issue_file_kind_tag(*kind),
+            }),
+            NodeKey::IssueHistory(key) => serde_json::json!({"kind": "issue_history", "key": key}),
+            NodeKey::IssueVersion(key, version_id) => serde_json::json!({
+                "kind": "issue_version",
+                "key": key,
+                "version_id": version_id,
+            }),
+            NodeKey::Query(name) => serde_json::json!({"kind": "query", "name": name}),
+            NodeKey::QueryJql(name) => serde_json::json!({"kind": "query_jql", "name": name}),
+            NodeKey::WorkerDir(id) => serde_json::json!({"kind": "worker_dir", "id": id.0}),
+            NodeKey::WorkerControl(id, command) => serde_json::json!({
+                "kind": "worker_control",
+                "id": id.0,
+                "command": worker_control_file_name(*command),
+            }),
+        }
+    }
+
+    fn from_journal_value(value: &serde_json::Value) -> Option<NodeKey> {
+        match value.get("kind")?.as_str()? {
+            "project" => Some(NodeKey::Project(value.get("name")?.as_str()?.to_string())),
+            "issue" => Some(NodeKey::Issue(
+                value.get("project")?.as_str()?.to_string(),
+                value.get("key")?.as_str()?.to_string(),
+                Self::issue_file_kind_from_tag(value.get("file_kind")?.as_str()?)?,
+            )),
+            "issue_history" => Some(NodeKey::IssueHistory(value.get("key")?.as_str()?.to_string())),
+            "issue_version" => Some(NodeKey::IssueVersion(
+                value.get("key")?.as_str()?.to_string(),
+                value.get("version_id")?.as_str()?.to_string(),
+            )),
+            "query" => Some(NodeKey::Query(value.get("name")?.as_str()?.to_string())),
+            "query_jql" => Some(NodeKey::QueryJql(value.get("name")?.as_str()?.to_string())),
+            "worker_dir" => Some(NodeKey::WorkerDir(WorkerId(value.get("id")?.as_u64()?))),
+            "worker_control" => Some(NodeKey::WorkerControl(
+                WorkerId(value.get("id")?.as_u64()?),
+                match value.get("command")?.as_str()? {
+                    "pause" => WorkerCommand::Pause,
+                    "resume" => WorkerCommand::Resume,
+                    "cancel" => WorkerCommand::Cancel,
+                    _ => return None,
+                },
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// First inode number handed out to dynamically discovered entities
+/// (projects, issues, queries, workers); everything below this is one of the
+/// fixed `INO_*` constants.
+const INO_DYNAMIC_START: u64 = 0x1_0000;
+
+#[derive(Debug, Default)]
+struct InodeAllocatorState {
+    forward: HashMap<NodeKey, INodeNo>,
+    next: u64,
+}
+
+/// Persistent, collision-free inode allocator.
+///
+/// Replaces hashing a `NodeKey` into a 64-bit inode (which risks birthday
+/// collisions once there are thousands of tickets) with a bijective map
+/// handed out from a monotonic counter. Assignments are appended to an
+/// on-disk JSONL journal so inode numbers survive remounts, since
+/// `readdir`/`open` cookies and client-side caches assume stability.
+#[derive(Debug)]
+struct InodeAllocator {
+    state: std::sync::Mutex<InodeAllocatorState>,
+    journal_path: Option<PathBuf>,
+}
+
+impl InodeAllocator {
+    fn new(journal_path: Option<PathBuf>) -> Self {
+        let mut forward = HashMap::new();
+        let mut next = INO_DYNAMIC_START;
+
+        if let Some(path) = &journal_path {
+            if let Ok(file) = std::fs::File::open(path) {
+                for line in io::BufReader::new(file).lines().map_while(Result::ok) {
+                    let Ok(record) = serde_json::from_str::<serde_json::Value>(&line) else {
+                        continue;
+                    };
+                    let Some(ino) = record.get("ino").and_then(serde_json::Value::as_u64) else {
+                        continue;
+                    };
+                    let Some(key) = record.get("key").and_then(NodeKey::from_journal_value) else {
+                        continue;
+                    };
+                    next = next.max(ino + 1);
+                    forward.insert(key, INodeNo(ino));
+                }
+            }
+        }
+
+        Self {
+            state: std::sync::Mutex::new(InodeAllocatorState { forward, next }),
+            journal_path,
+        }
+    }
+
+    fn alloc(&self, key: NodeKey) -> INodeNo {
+        let mut state = self.state.lock_or_recover("inode allocator");
+        if let Some(ino) = state.forward.get(&key) {
+            return *ino;
+        }
+
+        let ino = INodeNo(state.next);
+        state.next += 1;
+        state.forward.insert(key.clone(), ino);
+        drop(state);
+
+        if let Some(path) = &self.journal_path {
+            let mut record = key.to_journal_value();
+            record["ino"] = serde_json::json!(ino.0);
+            match std::fs::OpenOptions::new().create(true).append(true).open(path) {
+                Ok(mut file) => {
+                    if let Err(err) = writeln!(file, "{}", record) {
+                        logging::warn(format!("failed to journal inode assignment: {}", err));
+                    }
+                }
+                Err(err) => {
+                    logging::warn(format!("failed to open inode journal {:?}: {}", path, err));
+                }
+            }
+        }
+
+        ino
+    }
+}
+
 #[derive(Debug)]
 pub struct JiraFuseFs {
     uid: u32,
@@ -64,7 +295,15 @@ pub struct JiraFuseFs {
     sync_budget: usize,
     sync_state: Arc<SyncState>,
     initial_sync_started: AtomicBool,
+    periodic_sync_started: AtomicBool,
     state: std::sync::Mutex<FsState>,
+    workers: Arc<WorkerManager>,
+    scrub_status: std::sync::Mutex<Option<Arc<ScrubStatus>>>,
+    periodic_sync_status: std::sync::Mutex<Option<Arc<PeriodicSyncStatus>>>,
+    pending_issue_writes: std::sync::Mutex<HashMap<INodeNo, Vec<u8>>>,
+    queries: std::sync::Mutex<HashMap<String, String>>,
+    repair_report: std::sync::Mutex<String>,
+    inode_alloc: InodeAllocator,
 }
 
 impl JiraFuseFs {
@@ -77,9 +316,19 @@ impl JiraFuseFs {
         sync_budget: usize,
         sync_state: Arc<SyncState>,
     ) -> Self {
+        let journal_path = cache.persistent_dir().map(|dir| dir.join("inodes.jsonl"));
+        let inode_alloc = InodeAllocator::new(journal_path);
+
         let mut nodes = HashMap::new();
         nodes.insert(INodeNo::ROOT, Node::Root);
 
+        let mut queries = HashMap::new();
+        for (name, jql) in cache.list_queries() {
+            let ino = inode_alloc.alloc(NodeKey::Query(name.clone()));
+            nodes.insert(ino, Node::Query { name: name.clone() });
+            queries.insert(name, jql);
+        }
+
         Self {
             uid,
             gid,
@@ -89,10 +338,55 @@ impl JiraFuseFs {
             sync_budget,
             sync_state,
             initial_sync_started: AtomicBool::new(false),
+            periodic_sync_started: AtomicBool::new(false),
             state: std::sync::Mutex::new(FsState { nodes }),
+            workers: Arc::new(WorkerManager::new()),
+            scrub_status: std::sync::Mutex::new(None),
+            periodic_sync_status: std::sync::Mutex::new(None),
+            pending_issue_writes: std::sync::Mutex::new(HashMap::new()),
+            queries: std::sync::Mutex::new(queries),
+            repair_report: std::sync::Mutex::new("no repair run yet\n".to_string()),
+            inode_alloc,
         }
     }
 
+    fn inode_for_project(&self, project: &str) -> INodeNo {
+        self.inode_alloc.alloc(NodeKey::Project(project.to_string()))
+    }
+
+    fn inode_for_issue_kind(&self, project: &str, issue_key: &str, kind: IssueFileKind) -> INodeNo {
+        self.inode_alloc
+            .alloc(NodeKey::Issue(project.to_string(), issue_key.to_string(), kind))
+    }
+
+    fn inode_for_issue_history(&self, issue_key: &str) -> INodeNo {
+        self.inode_alloc
+            .alloc(NodeKey::IssueHistory(issue_key.to_string()))
+    }
+
+    fn inode_for_issue_version(&self, issue_key: &str, version_id: &str) -> INodeNo {
+        self.inode_alloc.alloc(NodeKey::IssueVersion(
+            issue_key.to_string(),
+            version_id.to_string(),
+        ))
+    }
+
+    fn inode_for_query(&self, name: &str) -> INodeNo {
+        self.inode_alloc.alloc(NodeKey::Query(name.to_string()))
+    }
+
+    fn inode_for_query_jql(&self, name: &str) -> INodeNo {
+        self.inode_alloc.alloc(NodeKey::QueryJql(name.to_string()))
+    }
+
+    fn inode_for_worker_dir(&self, id: WorkerId) -> INodeNo {
+        self.inode_alloc.alloc(NodeKey::WorkerDir(id))
+    }
+
+    fn inode_for_worker_control(&self, id: WorkerId, command: WorkerCommand) -> INodeNo {
+        self.inode_alloc.alloc(NodeKey::WorkerControl(id, command))
+    }
+
     fn spawn_initial_sync(&self) {
         if self.initial_sync_started.swap(true, Ordering::Relaxed) {
             return;
@@ -103,14 +397,29 @@ impl JiraFuseFs {
         let projects = self.projects.clone();
         let sync_budget = self.sync_budget;
         let sync_state = Arc::clone(&self.sync_state);
+        let (worker, commands) = self.workers.register("sync");
 
         std::thread::spawn(move || {
             if !sync_state.mark_sync_start() {
                 return;
             }
+            worker.set_state(crate::workers::WorkerState::Active);
+            worker.set_progress(0, projects.len());
+
+            let resume_from = cache.get_sync_checkpoint().filter(|checkpoint| {
+                projects.iter().any(|p| p == &checkpoint.project)
+            });
+            if let Some(checkpoint) = &resume_from {
+                logging::info(format!(
+                    "resuming sync from persisted checkpoint for {}",
+                    checkpoint.project
+                ));
+            } else {
+                logging::info("starting initial sync after mount...");
+            }
 
-            logging::info("starting initial sync after mount...");
-            let sync_result = sync_issues(&jira, &cache, &projects, sync_budget, false);
+            let sync_result =
+                sync_issues_resumable(&jira, &cache, &projects, sync_budget, false, resume_from);
 
             sync_state.mark_sync_complete();
             sync_state.mark_sync_end();
@@ -122,14 +431,159 @@ impl JiraFuseFs {
                 sync_result.errors.len()
             ));
 
-            if !sync_result.errors.is_empty() {
+            worker.set_progress(projects.len(), projects.len());
+            if sync_result.errors.is_empty() {
+                worker.set_state(crate::workers::WorkerState::Idle);
+            } else {
+                worker.set_last_error(sync_result.errors.last().cloned());
                 for err in &sync_result.errors {
                     logging::warn(format!("sync error: {}", err));
                 }
+                worker.set_state(crate::workers::WorkerState::Idle);
             }
+
+            // Drain any pending control commands; the one-shot initial sync
+            // doesn't act on them, but this keeps the channel from growing
+            // unbounded if a user writes to a control file mid-run.
+            while commands.try_recv().is_ok() {}
         });
     }
 
+    fn spawn_scrub(&self) {
+        let mut guard = self.scrub_status.lock_or_recover("scrub status");
+        if guard.is_some() {
+            return;
+        }
+        let status = spawn_scrub_worker(
+            Arc::clone(&self.jira),
+            Arc::clone(&self.cache),
+            self.projects.clone(),
+            Arc::clone(&self.sync_state),
+            &self.workers,
+            DEFAULT_SCRUB_TRANQUILITY,
+        );
+        *guard = Some(status);
+    }
+
+    /// Turns `SyncState`'s interval and manual-trigger fields into an actual
+    /// recurring sync loop, observable through the same `WorkerManager`
+    /// surface as the initial sync and scrub workers instead of sitting
+    /// unread.
+    fn spawn_periodic_sync(&self) {
+        if self.periodic_sync_started.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let status = spawn_periodic_sync_worker(
+            Arc::clone(&self.jira),
+            Arc::clone(&self.cache),
+            self.projects.clone(),
+            self.sync_budget,
+            Arc::clone(&self.sync_state),
+            &self.workers,
+            DEFAULT_SYNC_TRANQUILITY,
+        );
+        *self.periodic_sync_status.lock_or_recover("periodic sync status") = Some(status);
+    }
+
+    /// Re-validates the local cache against Jira and the inode table against
+    /// the local cache, analogous to a storage engine's manual recovery
+    /// command. Issues the tickets index still references but whose
+    /// markdown or comment sidecars are missing get refetched; inode-map
+    /// entries for issues Jira no longer reports get evicted as orphans.
+    /// Returns a human-readable summary written to `.sync_meta/repair_report`.
+    fn run_repair(&self) -> String {
+        let mut known_keys: HashSet<String> = HashSet::new();
+        for project in &self.projects {
+            if let Some(snapshot) = self.cache.get_project_issues_snapshot(project) {
+                known_keys.extend(snapshot.issues.into_iter().map(|issue| issue.key));
+            }
+        }
+
+        let mut refetched = 0usize;
+        let mut unrecoverable = 0usize;
+
+        if let Some(rows) = self.cache.list_ticket_index(&self.projects) {
+            for row in rows {
+                let main_present = self
+                    .cache
+                    .cached_issue_len(&row.id)
+                    .or_else(|| self.cache.persistent_issue_len(&row.id))
+                    .is_some();
+                let comments_present = self.cache.persistent_comments_md(&row.id).is_some();
+
+                if main_present && comments_present {
+                    continue;
+                }
+
+                if !known_keys.contains(&row.id) {
+                    // The index still references it, but Jira no longer does;
+                    // leave it for the orphan sweep below instead of guessing.
+                    unrecoverable += 1;
+                    continue;
+                }
+
+                let jql = format!("key = {}", row.id);
+                match self.jira.search_issues_bulk(&jql, 1) {
+                    Ok(issues) => match issues.first() {
+                        Some(fresh) => {
+                            let markdown = render_issue_markdown(fresh).into_bytes();
+                            self.cache.upsert_issue_direct(
+                                &fresh.key,
+                                &markdown,
+                                fresh.updated.as_deref(),
+                            );
+                            let sidecars = vec![(
+                                fresh.key.clone(),
+                                render_issue_comments_markdown(fresh).into_bytes(),
+                                fresh.updated.clone(),
+                            )];
+                            let _ = self.cache.upsert_issue_sidecars_batch(&sidecars);
+                            refetched += 1;
+                        }
+                        None => unrecoverable += 1,
+                    },
+                    Err(err) => {
+                        logging::warn(format!("repair: failed to refetch {}: {}", row.id, err));
+                        unrecoverable += 1;
+                    }
+                }
+            }
+        }
+
+        let evicted = if known_keys.is_empty() {
+            // Nothing has synced yet; don't treat that as "every issue is an
+            // orphan" and wipe the inode table out from under a cold mount.
+            0
+        } else {
+            let stale_inodes: Vec<INodeNo> = self
+                .state_guard()
+                .nodes
+                .iter()
+                .filter_map(|(ino, node)| match node {
+                    Node::Issue { key, .. } if !known_keys.contains(key) => Some(*ino),
+                    _ => None,
+                })
+                .collect();
+
+            let count = stale_inodes.len();
+            let mut guard = self.state_guard();
+            for ino in &stale_inodes {
+                guard.nodes.remove(ino);
+            }
+            count
+        };
+
+        logging::info(format!(
+            "repair complete: refetched={} evicted={} unrecoverable={}",
+            refetched, evicted, unrecoverable
+        ));
+
+        format!(
+            "repair complete: refetched={} evicted={} unrecoverable={}\n",
+            refetched, evicted, unrecoverable
+        )
+    }
+
     fn dir_attr(&self, ino: INodeNo) -> FileAttr {
         FileAttr {
             ino,
@@ -170,6 +624,25 @@ impl JiraFuseFs {
         }
     }
 
+    /// Like [`Self::file_attr`], but for a [`Node::Issue`] whose `mtime`
+    /// should reflect Jira's real `updated` time instead of the
+    /// `UNIX_EPOCH` placeholder every other node kind uses. Falls back to
+    /// `file_attr`'s placeholder when the issue has no cached or
+    /// unparseable `updated` value (e.g. never synced yet).
+    fn issue_file_attr(&self, ino: INodeNo, size: u64, writable: bool, issue_key: &str) -> FileAttr {
+        let mut attr = self.file_attr(ino, size, writable);
+        if let Some(updated_secs) = self
+            .cache
+            .source_updated_for_issue(issue_key)
+            .and_then(|raw| parse_jira_timestamp(&raw))
+        {
+            let mtime = UNIX_EPOCH + Duration::from_secs(updated_secs.max(0) as u64);
+            attr.mtime = mtime;
+            attr.ctime = mtime;
+        }
+        attr
+    }
+
     fn project_for_inode(&self, ino: INodeNo) -> Option<String> {
         let guard = self.state_guard();
         if let Some(Node::Project { name }) = guard.nodes.get(&ino) {
@@ -178,7 +651,7 @@ impl JiraFuseFs {
 
         self.projects
             .iter()
-            .find(|project| inode_for_project(project) == ino)
+            .find(|project| self.inode_for_project(project) == ino)
             .cloned()
     }
 
@@ -207,9 +680,36 @@ impl JiraFuseFs {
         if ino == INO_FULL_REFRESH {
             return Some(Node::SyncMetaFile);
         }
+        if ino == INO_RESUME_STATE {
+            return Some(Node::SyncMetaFile);
+        }
+        if ino == INO_SCRUB_TRANQUILITY || ino == INO_SCRUB_STATUS {
+            return Some(Node::SyncMetaFile);
+        }
+        if ino == INO_SYNC_TRANQUILITY || ino == INO_SYNC_STATUS {
+            return Some(Node::SyncMetaFile);
+        }
+        if ino == INO_RECONCILE {
+            return Some(Node::SyncMetaFile);
+        }
+        if ino == INO_REPAIR || ino == INO_REPAIR_REPORT {
+            return Some(Node::SyncMetaFile);
+        }
+        if ino == INO_TOMBSTONES {
+            return Some(Node::SyncMetaFile);
+        }
         if ino == INO_TICKETS_INDEX {
             return Some(Node::TicketsIndex);
         }
+        if ino == INO_WORKERS {
+            return Some(Node::Workers);
+        }
+        if ino == INO_WORKERS_INDEX {
+            return Some(Node::WorkersIndex);
+        }
+        if ino == INO_QUERIES {
+            return Some(Node::Queries);
+        }
 
         self.state_guard().nodes.get(&ino).cloned()
     }
@@ -241,6 +741,38 @@ impl JiraFuseFs {
         Ok(Vec::new())
     }
 
+    /// Returns the jql registered for a saved query, if any.
+    fn query_jql(&self, name: &str) -> Option<String> {
+        self.queries.lock_or_recover("queries").get(name).cloned()
+    }
+
+    /// Returns a saved query's matching issues, fetching from Jira on first read.
+    fn query_issues(&self, name: &str) -> Vec<crate::jira::IssueRef> {
+        let Some(jql) = self.query_jql(name).filter(|jql| !jql.is_empty()) else {
+            return Vec::new();
+        };
+
+        if let Some(snapshot) = self.cache.get_query_issues_snapshot(name) {
+            if !snapshot.is_stale {
+                return snapshot.issues;
+            }
+        }
+
+        match self.jira.list_issue_refs_for_jql(&jql) {
+            Ok(issues) => {
+                self.cache.upsert_query_issues(name, issues.clone());
+                issues
+            }
+            Err(err) => {
+                logging::warn(format!("failed to run saved query {}: {}", name, err));
+                self.cache
+                    .get_query_issues_snapshot(name)
+                    .map(|snapshot| snapshot.issues)
+                    .unwrap_or_default()
+            }
+        }
+    }
+
     fn issue_bytes(&self, issue_key: &str) -> Result<Vec<u8>, Errno> {
         self.cache.get_issue_markdown_stale_safe(issue_key, || {
             Err(Errno::EAGAIN)
@@ -294,6 +826,7 @@ impl JiraFuseFs {
                 .cache
                 .persistent_comments_jsonl_len(issue_key)
                 .unwrap_or(96),
+            IssueFileKind::NewComment => 0,
         }
     }
 
@@ -340,6 +873,30 @@ impl JiraFuseFs {
         Ok(out.into_bytes())
     }
 
+    /// Renders `.sync_meta/tombstones`: one JSON line per issue
+    /// `reconcile_projects` has evicted from scope and not yet garbage
+    /// collected, across every known project. The only consumer of
+    /// `InMemoryCache::list_tombstones` — without it, recorded tombstones
+    /// were invisible to anything outside a unit test.
+    fn tombstones_jsonl_bytes(&self) -> Vec<u8> {
+        let mut rows = Vec::new();
+        for project in &self.projects {
+            for tombstone in self.cache.list_tombstones(project) {
+                let line = serde_json::json!({
+                    "project": project,
+                    "id": tombstone.issue_key,
+                    "deleted_at": tombstone.deleted_at,
+                });
+                rows.push(line.to_string());
+            }
+        }
+        let mut out = rows.join("\n");
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.into_bytes()
+    }
+
     fn sync_meta_file_content(&self, ino: INodeNo) -> Vec<u8> {
         if ino == INO_LAST_SYNC {
             if let Some(last) = self.sync_state.last_sync() {
@@ -375,13 +932,90 @@ impl JiraFuseFs {
                 return b"write '1' or 'true' to trigger full upsert sync\n".to_vec();
             }
         }
+        if ino == INO_RESUME_STATE {
+            return match self.cache.get_sync_checkpoint() {
+                Some(checkpoint) => {
+                    let json = serde_json::json!({
+                        "project": checkpoint.project,
+                        "start_at": checkpoint.start_at,
+                        "remaining_budget": checkpoint.remaining_budget,
+                        "cached_this_run": checkpoint.cached_this_run,
+                    });
+                    format!("{}\n", json).into_bytes()
+                }
+                None => b"no checkpoint (last sync completed cleanly or hasn't run)\n".to_vec(),
+            };
+        }
+        if ino == INO_SCRUB_TRANQUILITY {
+            let value = self
+                .scrub_status
+                .lock_or_recover("scrub status")
+                .clone()
+                .map(|s| s.tranquility())
+                .unwrap_or(DEFAULT_SCRUB_TRANQUILITY);
+            return format!("{}\n", value).into_bytes();
+        }
+        if ino == INO_SCRUB_STATUS {
+            let json = self
+                .scrub_status
+                .lock_or_recover("scrub status")
+                .clone()
+                .map(|s| s.to_json())
+                .unwrap_or_else(|| {
+                    serde_json::json!({"state": "not started", "checked": 0, "refreshed": 0, "tranquility": DEFAULT_SCRUB_TRANQUILITY})
+                });
+            return format!("{}\n", json).into_bytes();
+        }
+        if ino == INO_SYNC_TRANQUILITY {
+            let value = self
+                .periodic_sync_status
+                .lock_or_recover("periodic sync status")
+                .clone()
+                .map(|s| s.tranquility())
+                .unwrap_or(DEFAULT_SYNC_TRANQUILITY);
+            return format!("{}\n", value).into_bytes();
+        }
+        if ino == INO_SYNC_STATUS {
+            let json = self
+                .periodic_sync_status
+                .lock_or_recover("periodic sync status")
+                .clone()
+                .map(|s| s.to_json())
+                .unwrap_or_else(|| {
+                    serde_json::json!({"state": "not started", "synced": 0, "skipped": 0, "tranquility": DEFAULT_SYNC_TRANQUILITY})
+                });
+            return format!("{}\n", json).into_bytes();
+        }
+        if ino == INO_RECONCILE {
+            return b"write '1' or 'true' to kick an immediate reconcile pass\n".to_vec();
+        }
+        if ino == INO_REPAIR {
+            return b"write '1' or 'true' to run a cache/inode repair scan\n".to_vec();
+        }
+        if ino == INO_REPAIR_REPORT {
+            return self.repair_report.lock_or_recover("repair report").clone().into_bytes();
+        }
+        if ino == INO_TOMBSTONES {
+            return self.tombstones_jsonl_bytes();
+        }
         b"unknown\n".to_vec()
     }
+
+    /// Returns the content of a query's `query.jql` control file.
+    fn query_jql_content(&self, name: &str) -> Vec<u8> {
+        match self.query_jql(name).filter(|jql| !jql.is_empty()) {
+            Some(jql) => format!("{}\n", jql).into_bytes(),
+            None => b"write a JQL filter to this file, e.g. project = PROJ AND status != Done\n"
+                .to_vec(),
+        }
+    }
 }
 
 impl Filesystem for JiraFuseFs {
     fn init(&mut self, _req: &Request, _config: &mut fuser::KernelConfig) -> io::Result<()> {
         self.spawn_initial_sync();
+        self.spawn_scrub();
+        self.spawn_periodic_sync();
         Ok(())
     }
 
@@ -399,10 +1033,186 @@ impl Filesystem for JiraFuseFs {
                 reply.entry(&TTL, &self.dir_attr(INO_TICKETS), Generation(0));
                 return;
             }
+            if name == OsStr::new("queries") {
+                reply.entry(&TTL, &self.dir_attr(INO_QUERIES), Generation(0));
+                return;
+            }
+            reply.error(Errno::ENOENT);
+            return;
+        }
+
+        if let Some(Node::IssueHistory { key }) = self.node_for_inode(parent) {
+            let Some(file_name) = name.to_str() else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+            let Some(version_id) = file_name
+                .strip_prefix(&format!("{key}@"))
+                .and_then(|rest| rest.strip_suffix(".md"))
+            else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+            if !self
+                .cache
+                .list_issue_history_versions(&key)
+                .iter()
+                .any(|v| v == version_id)
+            {
+                reply.error(Errno::ENOENT);
+                return;
+            }
+            let ino = self.inode_for_issue_version(&key, version_id);
+            self.upsert_node(
+                ino,
+                Node::IssueVersion {
+                    key: key.clone(),
+                    version_id: version_id.to_string(),
+                },
+            );
+            let size = self
+                .cache
+                .get_issue_history_version(&key, version_id)
+                .map(|bytes| bytes.len() as u64)
+                .unwrap_or(0);
+            reply.entry(&TTL, &self.file_attr(ino, size, false), Generation(0));
+            return;
+        }
+
+        if parent == INO_QUERIES {
+            let Some(name) = name.to_str() else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+            if self.queries.lock_or_recover("queries").contains_key(name) {
+                let ino = self.inode_for_query(name);
+                self.upsert_node(
+                    ino,
+                    Node::Query {
+                        name: name.to_string(),
+                    },
+                );
+                reply.entry(&TTL, &self.dir_attr(ino), Generation(0));
+                return;
+            }
+            reply.error(Errno::ENOENT);
+            return;
+        }
+
+        if let Some(Node::Query { name: query_name }) = self.node_for_inode(parent) {
+            if name == OsStr::new(QUERY_JQL_FILE_NAME) {
+                let ino = self.inode_for_query_jql(&query_name);
+                self.upsert_node(
+                    ino,
+                    Node::QueryJql {
+                        name: query_name.clone(),
+                    },
+                );
+                let size = self.query_jql_content(&query_name).len() as u64;
+                reply.entry(&TTL, &self.file_attr(ino, size, true), Generation(0));
+                return;
+            }
+
+            let Some(file_name) = name.to_str() else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+
+            if let Some(issue_key) = file_name.strip_suffix(".history") {
+                if self.query_issues(&query_name).iter().any(|issue| issue.key == issue_key) {
+                    let ino = self.inode_for_issue_history(issue_key);
+                    self.upsert_node(
+                        ino,
+                        Node::IssueHistory {
+                            key: issue_key.to_string(),
+                        },
+                    );
+                    reply.entry(&TTL, &self.dir_attr(ino), Generation(0));
+                    return;
+                }
+                reply.error(Errno::ENOENT);
+                return;
+            }
+
+            let (issue_key, kind) = if let Some(value) = file_name.strip_suffix(".comments.jsonl") {
+                (value, IssueFileKind::CommentsJsonl)
+            } else if let Some(value) = file_name.strip_suffix(".comments.md") {
+                (value, IssueFileKind::CommentsMarkdown)
+            } else if let Some(value) = file_name.strip_suffix(".new_comment.md") {
+                (value, IssueFileKind::NewComment)
+            } else if let Some(value) = file_name.strip_suffix(".md") {
+                (value, IssueFileKind::Main)
+            } else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+
+            let issues = self.query_issues(&query_name);
+            if !issues.iter().any(|issue| issue.key == issue_key) {
+                reply.error(Errno::ENOENT);
+                return;
+            }
+
+            let project = project_from_issue_key(issue_key);
+            let ino = self.inode_for_issue_kind(project, issue_key, kind);
+            self.upsert_node(
+                ino,
+                Node::Issue {
+                    key: issue_key.to_string(),
+                    kind,
+                },
+            );
+            let size = self.issue_sidecar_size(issue_key, kind);
+            let writable = kind == IssueFileKind::NewComment;
+            reply.entry(
+                &TTL,
+                &self.issue_file_attr(ino, size, writable, issue_key),
+                Generation(0),
+            );
+            return;
+        }
+
+        if parent == INO_WORKERS {
+            if name == OsStr::new("index.jsonl") {
+                let size = self.workers.index_jsonl().len() as u64;
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_WORKERS_INDEX, size, false),
+                    Generation(0),
+                );
+                return;
+            }
+            let Some(name) = name.to_str() else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+            if let Some(worker) = self.workers.worker_by_str(name) {
+                let ino = self.inode_for_worker_dir(worker.id);
+                self.upsert_node(ino, Node::WorkerDir { id: worker.id });
+                reply.entry(&TTL, &self.dir_attr(ino), Generation(0));
+                return;
+            }
             reply.error(Errno::ENOENT);
             return;
         }
 
+        if let Some(Node::WorkerDir { id }) = self.node_for_inode(parent) {
+            let command = match name.to_str() {
+                Some("pause") => Some(WorkerCommand::Pause),
+                Some("resume") => Some(WorkerCommand::Resume),
+                Some("cancel") => Some(WorkerCommand::Cancel),
+                _ => None,
+            };
+            let Some(command) = command else {
+                reply.error(Errno::ENOENT);
+                return;
+            };
+            let ino = self.inode_for_worker_control(id, command);
+            self.upsert_node(ino, Node::WorkerControl { id, command });
+            reply.entry(&TTL, &self.file_attr(ino, 0, true), Generation(0));
+            return;
+        }
+
         if parent == INO_TICKETS {
             if name == OsStr::new("index.jsonl") {
                 let size = self
@@ -466,13 +1276,98 @@ impl Filesystem for JiraFuseFs {
                 );
                 return;
             }
-            reply.error(Errno::ENOENT);
-            return;
+            if name == OsStr::new("workers") {
+                reply.entry(&TTL, &self.dir_attr(INO_WORKERS), Generation(0));
+                return;
+            }
+            if name == OsStr::new("resume_state") {
+                let content = self.sync_meta_file_content(INO_RESUME_STATE);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_RESUME_STATE, content.len() as u64, false),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("scrub_tranquility") {
+                let content = self.sync_meta_file_content(INO_SCRUB_TRANQUILITY);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_SCRUB_TRANQUILITY, content.len() as u64, true),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("scrub_status") {
+                let content = self.sync_meta_file_content(INO_SCRUB_STATUS);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_SCRUB_STATUS, content.len() as u64, false),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("sync_tranquility") {
+                let content = self.sync_meta_file_content(INO_SYNC_TRANQUILITY);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_SYNC_TRANQUILITY, content.len() as u64, true),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("sync_status") {
+                let content = self.sync_meta_file_content(INO_SYNC_STATUS);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_SYNC_STATUS, content.len() as u64, false),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("reconcile") {
+                let content = self.sync_meta_file_content(INO_RECONCILE);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_RECONCILE, content.len() as u64, true),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("repair") {
+                let content = self.sync_meta_file_content(INO_REPAIR);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_REPAIR, content.len() as u64, true),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("tombstones") {
+                let content = self.tombstones_jsonl_bytes();
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_TOMBSTONES, content.len() as u64, false),
+                    Generation(0),
+                );
+                return;
+            }
+            if name == OsStr::new("repair_report") {
+                let content = self.sync_meta_file_content(INO_REPAIR_REPORT);
+                reply.entry(
+                    &TTL,
+                    &self.file_attr(INO_REPAIR_REPORT, content.len() as u64, false),
+                    Generation(0),
+                );
+                return;
+            }
+            reply.error(Errno::ENOENT);
+            return;
         }
 
         if parent == INO_PROJECTS {
             if let Some(project) = self.projects.iter().find(|p| name == OsStr::new(p)) {
-                let ino = inode_for_project(project);
+                let ino = self.inode_for_project(project);
                 self.upsert_node(
                     ino,
                     Node::Project {
@@ -496,10 +1391,30 @@ impl Filesystem for JiraFuseFs {
             return;
         };
 
+        if let Some(issue_key) = file_name.strip_suffix(".history") {
+            if issue_key.starts_with(&format!("{project}-"))
+                && matches!(self.issue_exists_in_project(&project, issue_key), Ok(true))
+            {
+                let ino = self.inode_for_issue_history(issue_key);
+                self.upsert_node(
+                    ino,
+                    Node::IssueHistory {
+                        key: issue_key.to_string(),
+                    },
+                );
+                reply.entry(&TTL, &self.dir_attr(ino), Generation(0));
+                return;
+            }
+            reply.error(Errno::ENOENT);
+            return;
+        }
+
         let (issue_key, kind) = if let Some(value) = file_name.strip_suffix(".comments.jsonl") {
             (value, IssueFileKind::CommentsJsonl)
         } else if let Some(value) = file_name.strip_suffix(".comments.md") {
             (value, IssueFileKind::CommentsMarkdown)
+        } else if let Some(value) = file_name.strip_suffix(".new_comment.md") {
+            (value, IssueFileKind::NewComment)
         } else if let Some(value) = file_name.strip_suffix(".md") {
             (value, IssueFileKind::Main)
         } else {
@@ -514,7 +1429,7 @@ impl Filesystem for JiraFuseFs {
 
         match self.issue_exists_in_project(&project, issue_key) {
             Ok(true) => {
-                let ino = inode_for_issue_kind(&project, issue_key, kind);
+                let ino = self.inode_for_issue_kind(&project, issue_key, kind);
                 self.upsert_node(
                     ino,
                     Node::Issue {
@@ -526,8 +1441,14 @@ impl Filesystem for JiraFuseFs {
                     IssueFileKind::Main
                     | IssueFileKind::CommentsMarkdown
                     | IssueFileKind::CommentsJsonl => self.issue_sidecar_size(issue_key, kind),
+                    IssueFileKind::NewComment => 0,
                 };
-                reply.entry(&TTL, &self.file_attr(ino, size, false), Generation(0));
+                let writable = kind == IssueFileKind::NewComment;
+                reply.entry(
+                    &TTL,
+                    &self.issue_file_attr(ino, size, writable, issue_key),
+                    Generation(0),
+                );
             }
             Ok(false) => reply.error(Errno::ENOENT),
             Err(err) => reply.error(err),
@@ -540,7 +1461,7 @@ impl Filesystem for JiraFuseFs {
             return;
         }
 
-        if ino == INO_SYNC_META || ino == INO_PROJECTS || ino == INO_TICKETS {
+        if ino == INO_SYNC_META || ino == INO_PROJECTS || ino == INO_TICKETS || ino == INO_QUERIES {
             reply.attr(&TTL, &self.dir_attr(ino));
             return;
         }
@@ -550,9 +1471,22 @@ impl Filesystem for JiraFuseFs {
             || ino == INO_SECONDS_TO_NEXT
             || ino == INO_MANUAL_REFRESH
             || ino == INO_FULL_REFRESH
+            || ino == INO_RESUME_STATE
+            || ino == INO_SCRUB_TRANQUILITY
+            || ino == INO_SCRUB_STATUS
+            || ino == INO_REPAIR
+            || ino == INO_REPAIR_REPORT
+            || ino == INO_SYNC_TRANQUILITY
+            || ino == INO_SYNC_STATUS
+            || ino == INO_RECONCILE
         {
             let content = self.sync_meta_file_content(ino);
-            let writable = ino == INO_MANUAL_REFRESH || ino == INO_FULL_REFRESH;
+            let writable = ino == INO_MANUAL_REFRESH
+                || ino == INO_FULL_REFRESH
+                || ino == INO_SCRUB_TRANQUILITY
+                || ino == INO_REPAIR
+                || ino == INO_SYNC_TRANQUILITY
+                || ino == INO_RECONCILE;
             reply.attr(&TTL, &self.file_attr(ino, content.len() as u64, writable));
             return;
         }
@@ -566,7 +1500,24 @@ impl Filesystem for JiraFuseFs {
             return;
         }
 
-        if let Some(project) = self.projects.iter().find(|p| inode_for_project(p) == ino) {
+        if ino == INO_TOMBSTONES {
+            let size = self.tombstones_jsonl_bytes().len() as u64;
+            reply.attr(&TTL, &self.file_attr(ino, size, false));
+            return;
+        }
+
+        if ino == INO_WORKERS {
+            reply.attr(&TTL, &self.dir_attr(ino));
+            return;
+        }
+
+        if ino == INO_WORKERS_INDEX {
+            let size = self.workers.index_jsonl().len() as u64;
+            reply.attr(&TTL, &self.file_attr(ino, size, false));
+            return;
+        }
+
+        if let Some(project) = self.projects.iter().find(|p| self.inode_for_project(p) == ino) {
             self.upsert_node(
                 ino,
                 Node::Project {
@@ -579,14 +1530,38 @@ impl Filesystem for JiraFuseFs {
 
         match self.node_for_inode(ino) {
             Some(Node::Issue { key, kind }) => {
-                let size = match kind {
-                    IssueFileKind::Main
-                    | IssueFileKind::CommentsMarkdown
-                    | IssueFileKind::CommentsJsonl => self.issue_sidecar_size(&key, kind),
+                let pending_size = if matches!(kind, IssueFileKind::Main | IssueFileKind::NewComment)
+                {
+                    self.pending_issue_writes
+                        .lock_or_recover("pending issue writes")
+                        .get(&ino)
+                        .map(|buffer| buffer.len() as u64)
+                } else {
+                    None
                 };
-                reply.attr(&TTL, &self.file_attr(ino, size, false));
+                let size = pending_size.unwrap_or_else(|| self.issue_sidecar_size(&key, kind));
+                let writable = matches!(kind, IssueFileKind::Main | IssueFileKind::NewComment);
+                reply.attr(&TTL, &self.issue_file_attr(ino, size, writable, &key));
             }
             Some(Node::Project { .. }) => reply.attr(&TTL, &self.dir_attr(ino)),
+            Some(Node::IssueHistory { .. }) => reply.attr(&TTL, &self.dir_attr(ino)),
+            Some(Node::IssueVersion { key, version_id }) => {
+                let size = self
+                    .cache
+                    .get_issue_history_version(&key, &version_id)
+                    .map(|bytes| bytes.len() as u64)
+                    .unwrap_or(0);
+                reply.attr(&TTL, &self.file_attr(ino, size, false));
+            }
+            Some(Node::WorkerDir { .. }) => reply.attr(&TTL, &self.dir_attr(ino)),
+            Some(Node::WorkerControl { .. }) => reply.attr(&TTL, &self.file_attr(ino, 0, true)),
+            Some(Node::Queries) | Some(Node::Query { .. }) => {
+                reply.attr(&TTL, &self.dir_attr(ino))
+            }
+            Some(Node::QueryJql { name }) => {
+                let size = self.query_jql_content(&name).len() as u64;
+                reply.attr(&TTL, &self.file_attr(ino, size, true));
+            }
             _ => reply.error(Errno::ENOENT),
         }
     }
@@ -606,6 +1581,7 @@ impl Filesystem for JiraFuseFs {
                 (INO_SYNC_META, FileType::Directory, ".sync_meta".to_string()),
                 (INO_PROJECTS, FileType::Directory, "projects".to_string()),
                 (INO_TICKETS, FileType::Directory, "tickets".to_string()),
+                (INO_QUERIES, FileType::Directory, "queries".to_string()),
             ];
 
             for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
@@ -646,8 +1622,101 @@ impl Filesystem for JiraFuseFs {
                     FileType::RegularFile,
                     "full_refresh".to_string(),
                 ),
+                (INO_WORKERS, FileType::Directory, "workers".to_string()),
+                (
+                    INO_RESUME_STATE,
+                    FileType::RegularFile,
+                    "resume_state".to_string(),
+                ),
+                (
+                    INO_SCRUB_TRANQUILITY,
+                    FileType::RegularFile,
+                    "scrub_tranquility".to_string(),
+                ),
+                (
+                    INO_SCRUB_STATUS,
+                    FileType::RegularFile,
+                    "scrub_status".to_string(),
+                ),
+                (
+                    INO_SYNC_TRANQUILITY,
+                    FileType::RegularFile,
+                    "sync_tranquility".to_string(),
+                ),
+                (
+                    INO_SYNC_STATUS,
+                    FileType::RegularFile,
+                    "sync_status".to_string(),
+                ),
+                (
+                    INO_RECONCILE,
+                    FileType::RegularFile,
+                    "reconcile".to_string(),
+                ),
+                (INO_REPAIR, FileType::RegularFile, "repair".to_string()),
+                (
+                    INO_REPAIR_REPORT,
+                    FileType::RegularFile,
+                    "repair_report".to_string(),
+                ),
+                (
+                    INO_TOMBSTONES,
+                    FileType::RegularFile,
+                    "tombstones".to_string(),
+                ),
+            ];
+
+            for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
+                if reply.add(*entry_ino, (idx + 1) as u64, *kind, name) {
+                    break;
+                }
+            }
+            reply.ok();
+            return;
+        }
+
+        if ino == INO_WORKERS {
+            let mut entries: Vec<(INodeNo, FileType, String)> = vec![
+                (INO_WORKERS, FileType::Directory, ".".to_string()),
+                (INO_SYNC_META, FileType::Directory, "..".to_string()),
+                (
+                    INO_WORKERS_INDEX,
+                    FileType::RegularFile,
+                    "index.jsonl".to_string(),
+                ),
+            ];
+
+            for id in self.workers.ids() {
+                let dir_ino = self.inode_for_worker_dir(id);
+                self.upsert_node(dir_ino, Node::WorkerDir { id });
+                entries.push((dir_ino, FileType::Directory, id.to_string()));
+            }
+
+            for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
+                if reply.add(*entry_ino, (idx + 1) as u64, *kind, name) {
+                    break;
+                }
+            }
+            reply.ok();
+            return;
+        }
+
+        if let Some(Node::WorkerDir { id }) = self.node_for_inode(ino) {
+            let mut entries: Vec<(INodeNo, FileType, String)> = vec![
+                (ino, FileType::Directory, ".".to_string()),
+                (INO_WORKERS, FileType::Directory, "..".to_string()),
             ];
 
+            for command in [WorkerCommand::Pause, WorkerCommand::Resume, WorkerCommand::Cancel] {
+                let ctrl_ino = self.inode_for_worker_control(id, command);
+                self.upsert_node(ctrl_ino, Node::WorkerControl { id, command });
+                entries.push((
+                    ctrl_ino,
+                    FileType::RegularFile,
+                    worker_control_file_name(command).to_string(),
+                ));
+            }
+
             for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
                 if reply.add(*entry_ino, (idx + 1) as u64, *kind, name) {
                     break;
@@ -664,7 +1733,7 @@ impl Filesystem for JiraFuseFs {
             ];
 
             for project in &self.projects {
-                let p_ino = inode_for_project(project);
+                let p_ino = self.inode_for_project(project);
                 self.upsert_node(
                     p_ino,
                     Node::Project {
@@ -703,6 +1772,157 @@ impl Filesystem for JiraFuseFs {
             return;
         }
 
+        if ino == INO_QUERIES {
+            let mut entries: Vec<(INodeNo, FileType, String)> = vec![
+                (INO_QUERIES, FileType::Directory, ".".to_string()),
+                (INodeNo::ROOT, FileType::Directory, "..".to_string()),
+            ];
+
+            for name in self.queries.lock_or_recover("queries").keys() {
+                let q_ino = self.inode_for_query(name);
+                self.upsert_node(
+                    q_ino,
+                    Node::Query {
+                        name: name.clone(),
+                    },
+                );
+                entries.push((q_ino, FileType::Directory, name.clone()));
+            }
+
+            for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
+                if reply.add(*entry_ino, (idx + 1) as u64, *kind, name) {
+                    break;
+                }
+            }
+            reply.ok();
+            return;
+        }
+
+        if let Some(Node::Query { name }) = self.node_for_inode(ino) {
+            let jql_ino = self.inode_for_query_jql(&name);
+            self.upsert_node(jql_ino, Node::QueryJql { name: name.clone() });
+
+            let mut entries: Vec<(INodeNo, FileType, String)> = vec![
+                (ino, FileType::Directory, ".".to_string()),
+                (INO_QUERIES, FileType::Directory, "..".to_string()),
+                (jql_ino, FileType::RegularFile, QUERY_JQL_FILE_NAME.to_string()),
+            ];
+
+            for issue in self.query_issues(&name) {
+                let project = project_from_issue_key(&issue.key).to_string();
+                let issue_ino = self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::Main);
+                let comments_md_ino =
+                    self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::CommentsMarkdown);
+                let comments_jsonl_ino =
+                    self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::CommentsJsonl);
+                let new_comment_ino =
+                    self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::NewComment);
+                self.upsert_node(
+                    issue_ino,
+                    Node::Issue {
+                        key: issue.key.clone(),
+                        kind: IssueFileKind::Main,
+                    },
+                );
+                self.upsert_node(
+                    comments_md_ino,
+                    Node::Issue {
+                        key: issue.key.clone(),
+                        kind: IssueFileKind::CommentsMarkdown,
+                    },
+                );
+                self.upsert_node(
+                    comments_jsonl_ino,
+                    Node::Issue {
+                        key: issue.key.clone(),
+                        kind: IssueFileKind::CommentsJsonl,
+                    },
+                );
+                self.upsert_node(
+                    new_comment_ino,
+                    Node::Issue {
+                        key: issue.key.clone(),
+                        kind: IssueFileKind::NewComment,
+                    },
+                );
+                let history_ino = self.inode_for_issue_history(&issue.key);
+                self.upsert_node(
+                    history_ino,
+                    Node::IssueHistory {
+                        key: issue.key.clone(),
+                    },
+                );
+                entries.push((
+                    issue_ino,
+                    FileType::RegularFile,
+                    format!("{}.md", issue.key),
+                ));
+                entries.push((
+                    comments_md_ino,
+                    FileType::RegularFile,
+                    format!("{}.comments.md", issue.key),
+                ));
+                entries.push((
+                    comments_jsonl_ino,
+                    FileType::RegularFile,
+                    format!("{}.comments.jsonl", issue.key),
+                ));
+                entries.push((
+                    new_comment_ino,
+                    FileType::RegularFile,
+                    format!("{}.new_comment.md", issue.key),
+                ));
+                entries.push((
+                    history_ino,
+                    FileType::Directory,
+                    format!("{}.history", issue.key),
+                ));
+            }
+
+            for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
+                if reply.add(*entry_ino, (idx + 1) as u64, *kind, name) {
+                    break;
+                }
+            }
+            reply.ok();
+            return;
+        }
+
+        if let Some(Node::IssueHistory { key }) = self.node_for_inode(ino) {
+            let mut entries: Vec<(INodeNo, FileType, String)> = vec![
+                (ino, FileType::Directory, ".".to_string()),
+                (
+                    self.inode_for_project(project_from_issue_key(&key)),
+                    FileType::Directory,
+                    "..".to_string(),
+                ),
+            ];
+
+            for version_id in self.cache.list_issue_history_versions(&key) {
+                let version_ino = self.inode_for_issue_version(&key, &version_id);
+                self.upsert_node(
+                    version_ino,
+                    Node::IssueVersion {
+                        key: key.clone(),
+                        version_id: version_id.clone(),
+                    },
+                );
+                entries.push((
+                    version_ino,
+                    FileType::RegularFile,
+                    format!("{}@{}.md", key, version_id),
+                ));
+            }
+
+            for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
+                if reply.add(*entry_ino, (idx + 1) as u64, *kind, name) {
+                    break;
+                }
+            }
+            reply.ok();
+            return;
+        }
+
         let Some(project) = self.project_for_inode(ino) else {
             reply.error(Errno::ENOENT);
             return;
@@ -722,11 +1942,13 @@ impl Filesystem for JiraFuseFs {
         ];
 
         for issue in issues {
-            let issue_ino = inode_for_issue_kind(&project, &issue.key, IssueFileKind::Main);
+            let issue_ino = self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::Main);
             let comments_md_ino =
-                inode_for_issue_kind(&project, &issue.key, IssueFileKind::CommentsMarkdown);
+                self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::CommentsMarkdown);
             let comments_jsonl_ino =
-                inode_for_issue_kind(&project, &issue.key, IssueFileKind::CommentsJsonl);
+                self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::CommentsJsonl);
+            let new_comment_ino =
+                self.inode_for_issue_kind(&project, &issue.key, IssueFileKind::NewComment);
             self.upsert_node(
                 issue_ino,
                 Node::Issue {
@@ -748,6 +1970,20 @@ impl Filesystem for JiraFuseFs {
                     kind: IssueFileKind::CommentsJsonl,
                 },
             );
+            self.upsert_node(
+                new_comment_ino,
+                Node::Issue {
+                    key: issue.key.clone(),
+                    kind: IssueFileKind::NewComment,
+                },
+            );
+            let history_ino = self.inode_for_issue_history(&issue.key);
+            self.upsert_node(
+                history_ino,
+                Node::IssueHistory {
+                    key: issue.key.clone(),
+                },
+            );
             entries.push((
                 issue_ino,
                 FileType::RegularFile,
@@ -763,6 +1999,16 @@ impl Filesystem for JiraFuseFs {
                 FileType::RegularFile,
                 format!("{}.comments.jsonl", issue.key),
             ));
+            entries.push((
+                new_comment_ino,
+                FileType::RegularFile,
+                format!("{}.new_comment.md", issue.key),
+            ));
+            entries.push((
+                history_ino,
+                FileType::Directory,
+                format!("{}.history", issue.key),
+            ));
         }
 
         for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
@@ -774,21 +2020,70 @@ impl Filesystem for JiraFuseFs {
     }
 
     fn open(&self, _req: &Request, ino: INodeNo, flags: OpenFlags, reply: ReplyOpen) {
-        let is_writable_file = ino == INO_MANUAL_REFRESH || ino == INO_FULL_REFRESH;
+        let is_issue_main = matches!(
+            self.node_for_inode(ino),
+            Some(Node::Issue {
+                kind: IssueFileKind::Main,
+                ..
+            })
+        );
+        let is_new_comment = matches!(
+            self.node_for_inode(ino),
+            Some(Node::Issue {
+                kind: IssueFileKind::NewComment,
+                ..
+            })
+        );
+        let is_writable_file = ino == INO_MANUAL_REFRESH
+            || ino == INO_FULL_REFRESH
+            || ino == INO_SCRUB_TRANQUILITY
+            || ino == INO_SYNC_TRANQUILITY
+            || ino == INO_REPAIR
+            || ino == INO_RECONCILE
+            || is_issue_main
+            || is_new_comment
+            || matches!(self.node_for_inode(ino), Some(Node::WorkerControl { .. }))
+            || matches!(self.node_for_inode(ino), Some(Node::QueryJql { .. }));
 
         if flags.acc_mode() != OpenAccMode::O_RDONLY && !is_writable_file {
             reply.error(Errno::EROFS);
             return;
         }
 
-        match self.node_for_inode(ino) {
-            Some(Node::Issue { .. }) | Some(Node::SyncMetaFile) | Some(Node::TicketsIndex) => {
-                reply.opened(FileHandle(0), FopenFlags::empty())
+        if flags.acc_mode() != OpenAccMode::O_RDONLY && is_issue_main {
+            if let Some(Node::Issue { key, .. }) = self.node_for_inode(ino) {
+                let seed = self.issue_bytes(&key).unwrap_or_default();
+                self.pending_issue_writes
+                    .lock_or_recover("pending issue writes")
+                    .entry(ino)
+                    .or_insert(seed);
             }
+        }
+
+        if flags.acc_mode() != OpenAccMode::O_RDONLY && is_new_comment {
+            self.pending_issue_writes
+                .lock_or_recover("pending issue writes")
+                .entry(ino)
+                .or_default();
+        }
+
+        match self.node_for_inode(ino) {
+            Some(Node::Issue { .. })
+            | Some(Node::IssueVersion { .. })
+            | Some(Node::SyncMetaFile)
+            | Some(Node::TicketsIndex)
+            | Some(Node::WorkersIndex)
+            | Some(Node::WorkerControl { .. })
+            | Some(Node::QueryJql { .. }) => reply.opened(FileHandle(0), FopenFlags::empty()),
             Some(Node::Project { .. })
+            | Some(Node::IssueHistory { .. })
             | Some(Node::SyncMeta)
             | Some(Node::Projects)
             | Some(Node::Tickets)
+            | Some(Node::Workers)
+            | Some(Node::WorkerDir { .. })
+            | Some(Node::Queries)
+            | Some(Node::Query { .. })
             | Some(Node::Root) => reply.error(Errno::EISDIR),
             None => reply.error(Errno::ENOENT),
         }
@@ -810,6 +2105,14 @@ impl Filesystem for JiraFuseFs {
             || ino == INO_SECONDS_TO_NEXT
             || ino == INO_MANUAL_REFRESH
             || ino == INO_FULL_REFRESH
+            || ino == INO_RESUME_STATE
+            || ino == INO_SCRUB_TRANQUILITY
+            || ino == INO_SCRUB_STATUS
+            || ino == INO_REPAIR
+            || ino == INO_REPAIR_REPORT
+            || ino == INO_SYNC_TRANQUILITY
+            || ino == INO_SYNC_STATUS
+            || ino == INO_RECONCILE
         {
             let data = self.sync_meta_file_content(ino);
             let start = offset as usize;
@@ -840,6 +2143,73 @@ impl Filesystem for JiraFuseFs {
             return;
         }
 
+        if ino == INO_TOMBSTONES {
+            let data = self.tombstones_jsonl_bytes();
+            let start = offset as usize;
+            if start >= data.len() {
+                reply.data(&[]);
+                return;
+            }
+            let end = start.saturating_add(size as usize).min(data.len());
+            reply.data(&data[start..end]);
+            return;
+        }
+
+        if ino == INO_WORKERS_INDEX {
+            let data = self.workers.index_jsonl();
+            let start = offset as usize;
+            if start >= data.len() {
+                reply.data(&[]);
+                return;
+            }
+            let end = start.saturating_add(size as usize).min(data.len());
+            reply.data(&data[start..end]);
+            return;
+        }
+
+        if let Some(Node::QueryJql { name }) = self.node_for_inode(ino) {
+            let data = self.query_jql_content(&name);
+            let start = offset as usize;
+            if start >= data.len() {
+                reply.data(&[]);
+                return;
+            }
+            let end = start.saturating_add(size as usize).min(data.len());
+            reply.data(&data[start..end]);
+            return;
+        }
+
+        if let Some(Node::WorkerControl { command, .. }) = self.node_for_inode(ino) {
+            let data = format!(
+                "write '1' or 'true' to {}\n",
+                worker_control_file_name(command)
+            )
+            .into_bytes();
+            let start = offset as usize;
+            if start >= data.len() {
+                reply.data(&[]);
+                return;
+            }
+            let end = start.saturating_add(size as usize).min(data.len());
+            reply.data(&data[start..end]);
+            return;
+        }
+
+        if let Some(Node::IssueVersion { key, version_id }) = self.node_for_inode(ino) {
+            let data = self
+                .cache
+                .get_issue_history_version(&key, &version_id)
+                .unwrap_or_default();
+            let start = offset as usize;
+            if start >= data.len() {
+                reply.data(&[]);
+                return;
+            }
+            let end = start.saturating_add(size as usize).min(data.len());
+            reply.data(&data[start..end]);
+            return;
+        }
+
         let Some(Node::Issue { key, kind }) = self.node_for_inode(ino) else {
             reply.error(Errno::ENOENT);
             return;
@@ -849,6 +2219,7 @@ impl Filesystem for JiraFuseFs {
             IssueFileKind::Main => self.issue_bytes(&key),
             IssueFileKind::CommentsMarkdown => self.issue_comments_markdown_bytes(&key),
             IssueFileKind::CommentsJsonl => self.issue_comments_jsonl_bytes(&key),
+            IssueFileKind::NewComment => Ok(Vec::new()),
         };
 
         let data = match data {
@@ -880,6 +2251,140 @@ impl Filesystem for JiraFuseFs {
         _lock_owner: Option<fuser::LockOwner>,
         reply: ReplyWrite,
     ) {
+        if let Some(Node::Issue {
+            kind: IssueFileKind::Main | IssueFileKind::NewComment,
+            ..
+        }) = self.node_for_inode(ino)
+        {
+            let mut guard = self.pending_issue_writes.lock_or_recover("pending issue writes");
+            let buffer = guard.entry(ino).or_default();
+            let start = offset as usize;
+            let end = start + data.len();
+            if buffer.len() < end {
+                buffer.resize(end, 0);
+            }
+            buffer[start..end].copy_from_slice(data);
+            reply.written(data.len() as u32);
+            return;
+        }
+
+        if let Some(Node::QueryJql { name }) = self.node_for_inode(ino) {
+            if offset != 0 {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+
+            let jql = String::from_utf8_lossy(data).trim().to_string();
+            self.queries
+                .lock_or_recover("queries")
+                .insert(name.clone(), jql.clone());
+            self.cache.upsert_query(&name, &jql);
+
+            match self.jira.list_issue_refs_for_jql(&jql) {
+                Ok(issues) => self.cache.upsert_query_issues(&name, issues),
+                Err(err) => {
+                    logging::warn(format!("failed to run saved query {}: {}", name, err));
+                }
+            }
+
+            reply.written(data.len() as u32);
+            return;
+        }
+
+        if let Some(Node::WorkerControl { id, command }) = self.node_for_inode(ino) {
+            if offset != 0 {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+
+            let content = String::from_utf8_lossy(data).to_lowercase();
+            let trimmed = content.trim();
+
+            if trimmed == "1" || trimmed == "true" {
+                if let Some(worker) = self.workers.worker(id) {
+                    worker.send(command);
+                    logging::info(format!(
+                        "worker {} control {} triggered",
+                        id,
+                        worker_control_file_name(command)
+                    ));
+                }
+            }
+
+            reply.written(data.len() as u32);
+            return;
+        }
+
+        if ino == INO_SCRUB_TRANQUILITY {
+            if offset != 0 {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+            let content = String::from_utf8_lossy(data);
+            match content.trim().parse::<u64>() {
+                Ok(value) => {
+                    if let Some(status) = self.scrub_status.lock_or_recover("scrub status").clone() {
+                        status.set_tranquility(value);
+                    }
+                    reply.written(data.len() as u32);
+                }
+                Err(_) => reply.error(Errno::EINVAL),
+            }
+            return;
+        }
+
+        if ino == INO_SYNC_TRANQUILITY {
+            if offset != 0 {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+            let content = String::from_utf8_lossy(data);
+            match content.trim().parse::<u64>() {
+                Ok(value) => {
+                    if let Some(status) = self
+                        .periodic_sync_status
+                        .lock_or_recover("periodic sync status")
+                        .clone()
+                    {
+                        status.set_tranquility(value);
+                    }
+                    reply.written(data.len() as u32);
+                }
+                Err(_) => reply.error(Errno::EINVAL),
+            }
+            return;
+        }
+
+        if ino == INO_RECONCILE {
+            if offset != 0 {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+            let content = String::from_utf8_lossy(data).to_lowercase();
+            let trimmed = content.trim();
+            if trimmed == "1" || trimmed == "true" {
+                self.sync_state.trigger_manual_reconcile();
+                logging::info("manual reconcile triggered via .sync_meta/reconcile");
+            }
+            reply.written(data.len() as u32);
+            return;
+        }
+
+        if ino == INO_REPAIR {
+            if offset != 0 {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+            let content = String::from_utf8_lossy(data).to_lowercase();
+            let trimmed = content.trim();
+            if trimmed == "1" || trimmed == "true" {
+                let report = self.run_repair();
+                *self.repair_report.lock_or_recover("repair report") = report;
+            }
+            reply.written(data.len() as u32);
+            return;
+        }
+
         if ino != INO_MANUAL_REFRESH && ino != INO_FULL_REFRESH {
             reply.error(Errno::EROFS);
             return;
@@ -906,6 +2411,76 @@ impl Filesystem for JiraFuseFs {
         reply.written(data.len() as u32);
     }
 
+    fn mkdir(
+        &self,
+        _req: &Request,
+        parent: INodeNo,
+        name: &OsStr,
+        _mode: u32,
+        _umask: u32,
+        reply: ReplyEntry,
+    ) {
+        if parent != INO_QUERIES {
+            reply.error(Errno::EROFS);
+            return;
+        }
+
+        let Some(name) = name.to_str() else {
+            reply.error(Errno::EINVAL);
+            return;
+        };
+
+        if self.queries.lock_or_recover("queries").contains_key(name) {
+            reply.error(Errno::EEXIST);
+            return;
+        }
+
+        self.cache.upsert_query(name, "");
+        self.queries
+            .lock_or_recover("queries")
+            .insert(name.to_string(), String::new());
+
+        let ino = self.inode_for_query(name);
+        self.upsert_node(
+            ino,
+            Node::Query {
+                name: name.to_string(),
+            },
+        );
+        logging::info(format!("created query directory {}", name));
+        reply.entry(&TTL, &self.dir_attr(ino), Generation(0));
+    }
+
+    fn rmdir(&self, _req: &Request, parent: INodeNo, name: &OsStr, reply: ReplyEmpty) {
+        if parent != INO_QUERIES {
+            reply.error(Errno::EROFS);
+            return;
+        }
+
+        let Some(name) = name.to_str() else {
+            reply.error(Errno::ENOENT);
+            return;
+        };
+
+        if self
+            .queries
+            .lock_or_recover("queries")
+            .remove(name)
+            .is_none()
+        {
+            reply.error(Errno::ENOENT);
+            return;
+        }
+
+        self.cache.remove_query(name);
+        self.state_guard().nodes.remove(&self.inode_for_query(name));
+        self.state_guard()
+            .nodes
+            .remove(&self.inode_for_query_jql(name));
+        logging::info(format!("removed query directory {}", name));
+        reply.ok();
+    }
+
     fn setattr(
         &self,
         _req: &Request,
@@ -929,79 +2504,336 @@ impl Filesystem for JiraFuseFs {
             reply.attr(&TTL, &self.file_attr(ino, content.len() as u64, true));
             return;
         }
+
+        if matches!(
+            self.node_for_inode(ino),
+            Some(Node::Issue {
+                kind: IssueFileKind::Main | IssueFileKind::NewComment,
+                ..
+            })
+        ) {
+            let mut guard = self.pending_issue_writes.lock_or_recover("pending issue writes");
+            let buffer = guard.entry(ino).or_default();
+            if let Some(size) = _size {
+                buffer.resize(size as usize, 0);
+            }
+            reply.attr(&TTL, &self.file_attr(ino, buffer.len() as u64, true));
+            return;
+        }
+
         reply.error(Errno::EROFS);
     }
-}
 
-pub fn inode_for_project(project: &str) -> INodeNo {
-    INodeNo(namespace_hash(0x11, project.as_bytes()))
-}
+    /// Blocks the calling `release` up to [`RELEASE_SYNC_WAIT_MAX`], polling
+    /// every [`RELEASE_SYNC_WAIT_POLL`], until `SyncState` reports no sync in
+    /// progress. Returns `true` once idle (immediately, if already idle),
+    /// `false` if the wait timed out and a sync is still running.
+    fn wait_for_sync_idle(&self) -> bool {
+        let start = Instant::now();
+        while self.sync_state.is_sync_in_progress() {
+            if start.elapsed() >= RELEASE_SYNC_WAIT_MAX {
+                return false;
+            }
+            std::thread::sleep(RELEASE_SYNC_WAIT_POLL);
+        }
+        true
+    }
 
-pub fn inode_for_issue(project: &str, issue_key: &str) -> INodeNo {
-    let mut bytes = project.as_bytes().to_vec();
-    bytes.push(b'/');
-    bytes.extend_from_slice(issue_key.as_bytes());
-    INodeNo(namespace_hash(0x22, &bytes))
-}
+    fn release(
+        &self,
+        _req: &Request,
+        ino: INodeNo,
+        _fh: FileHandle,
+        _flags: OpenFlags,
+        _lock_owner: Option<fuser::LockOwner>,
+        _flush: bool,
+        reply: ReplyEmpty,
+    ) {
+        if let Some(Node::Issue {
+            key,
+            kind: IssueFileKind::NewComment,
+        }) = self.node_for_inode(ino)
+        {
+            let buffer = self
+                .pending_issue_writes
+                .lock_or_recover("pending issue writes")
+                .get(&ino)
+                .cloned();
+            let Some(buffer) = buffer else {
+                reply.ok();
+                return;
+            };
+
+            if !self.wait_for_sync_idle() {
+                // A sync has been running longer than we're willing to block
+                // `close()` for. The buffer stays staged, but nothing retries
+                // it automatically — most callers don't retry a failed
+                // close, so this is a real (if rare) dropped-edit risk, not
+                // a guaranteed deferral.
+                logging::warn(format!(
+                    "timed out waiting for sync to finish before posting comment to {}",
+                    key
+                ));
+                reply.error(Errno::EAGAIN);
+                return;
+            }
+
+            let body = String::from_utf8_lossy(&buffer).trim().to_string();
+            if body.is_empty() {
+                reply.ok();
+                return;
+            }
+
+            match writeback::post_comment(&self.jira, &key, &body) {
+                Ok(()) => {
+                    self.pending_issue_writes
+                        .lock_or_recover("pending issue writes")
+                        .remove(&ino);
+
+                    let markdown = format!("**comment** ({}): {}\n", unix_epoch_seconds_string(), body);
+                    let jsonl = format!(
+                        "{}\n",
+                        serde_json::json!({
+                            "body": body,
+                            "created_at": unix_epoch_seconds_string(),
+                        })
+                    );
+                    self.cache.append_issue_comment(&key, &markdown, &jsonl);
+                    reply.ok();
+                }
+                Err(writeback::WriteBackError::JiraError(msg)) => {
+                    logging::warn(format!("failed to post comment to {}: {}", key, msg));
+                    reply.error(Errno::EIO);
+                }
+                Err(_) => {
+                    reply.error(Errno::EIO);
+                }
+            }
+            return;
+        }
+
+        let Some(Node::Issue {
+            key,
+            kind: IssueFileKind::Main,
+        }) = self.node_for_inode(ino)
+        else {
+            reply.ok();
+            return;
+        };
+
+        let buffer = self
+            .pending_issue_writes
+            .lock_or_recover("pending issue writes")
+            .get(&ino)
+            .cloned();
+        let Some(buffer) = buffer else {
+            reply.ok();
+            return;
+        };
+
+        if !self.wait_for_sync_idle() {
+            // A sync has been running longer than we're willing to block
+            // `close()` for. The buffer stays staged, but nothing retries it
+            // automatically — most callers don't retry a failed close, so
+            // this is a real (if rare) dropped-edit risk, not a guaranteed
+            // deferral.
+            logging::warn(format!(
+                "timed out waiting for sync to finish before pushing changes to {}",
+                key
+            ));
+            reply.error(Errno::EAGAIN);
+            return;
+        }
+
+        let edited = match parse_front_matter(&buffer) {
+            Ok((front_matter, _body)) => front_matter,
+            Err(writeback::WriteBackError::InvalidFrontMatter(msg)) => {
+                logging::warn(format!("invalid front-matter for {}: {}", key, msg));
+                reply.error(Errno::EINVAL);
+                return;
+            }
+            Err(_) => {
+                reply.error(Errno::EINVAL);
+                return;
+            }
+        };
 
-fn inode_for_issue_kind(project: &str, issue_key: &str, kind: IssueFileKind) -> INodeNo {
-    match kind {
-        IssueFileKind::Main => inode_for_issue(project, issue_key),
-        IssueFileKind::CommentsMarkdown => {
-            let mut bytes = project.as_bytes().to_vec();
-            bytes.push(b'/');
-            bytes.extend_from_slice(issue_key.as_bytes());
-            bytes.extend_from_slice(b"#comments.md");
-            INodeNo(namespace_hash(0x23, &bytes))
+        let original = self
+            .issue_bytes(&key)
+            .ok()
+            .and_then(|bytes| parse_front_matter(&bytes).ok())
+            .map(|(front_matter, _body)| front_matter)
+            .unwrap_or_default();
+
+        let changes = writeback::diff_front_matter(&edited, &original);
+        if changes.is_empty() {
+            self.pending_issue_writes
+                .lock_or_recover("pending issue writes")
+                .remove(&ino);
+            reply.ok();
+            return;
         }
-        IssueFileKind::CommentsJsonl => {
-            let mut bytes = project.as_bytes().to_vec();
-            bytes.push(b'/');
-            bytes.extend_from_slice(issue_key.as_bytes());
-            bytes.extend_from_slice(b"#comments.jsonl");
-            INodeNo(namespace_hash(0x24, &bytes))
+
+        match writeback::push_changes(&self.jira, &key, &changes) {
+            Ok(()) => {
+                self.pending_issue_writes
+                    .lock_or_recover("pending issue writes")
+                    .remove(&ino);
+                reply.ok();
+            }
+            Err(writeback::WriteBackError::UnknownTransition(status)) => {
+                logging::warn(format!("unknown transition '{}' for {}", status, key));
+                reply.error(Errno::EINVAL);
+            }
+            Err(writeback::WriteBackError::InvalidFrontMatter(msg)) => {
+                logging::warn(format!("invalid front-matter for {}: {}", key, msg));
+                reply.error(Errno::EINVAL);
+            }
+            Err(writeback::WriteBackError::JiraError(msg)) => {
+                logging::warn(format!("failed to push changes for {}: {}", key, msg));
+                reply.error(Errno::EIO);
+            }
         }
     }
 }
 
-fn namespace_hash(namespace: u8, bytes: &[u8]) -> u64 {
-    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
-    hash ^= u64::from(namespace);
-    hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
-    for b in bytes {
-        hash ^= u64::from(*b);
-        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
-    }
+/// Derives the project key from an issue key like `PROJ-123`.
+fn project_from_issue_key(issue_key: &str) -> &str {
+    issue_key.split_once('-').map_or(issue_key, |(project, _)| project)
+}
 
-    let value = hash | (1_u64 << 63);
-    if value == 1 {
-        3
-    } else {
-        value
+fn unix_epoch_seconds_string() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs().to_string())
+        .unwrap_or_else(|_| {
+            logging::warn("system clock before unix epoch; using fallback timestamp 0");
+            "0".to_string()
+        })
+}
+
+fn worker_control_file_name(command: WorkerCommand) -> &'static str {
+    match command {
+        WorkerCommand::Pause => "pause",
+        WorkerCommand::Resume => "resume",
+        WorkerCommand::Cancel => "cancel",
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::AtomicU64;
+
+    fn unique_journal_path() -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("fs_jira_inode_journal_test_{}_{}", std::process::id(), n))
+    }
 
     #[test]
     fn project_inode_is_deterministic() {
-        assert_eq!(inode_for_project("PROJ"), inode_for_project("PROJ"));
+        let alloc = InodeAllocator::new(None);
+        let a = alloc.alloc(NodeKey::Project("PROJ".to_string()));
+        let b = alloc.alloc(NodeKey::Project("PROJ".to_string()));
+        assert_eq!(a, b);
     }
 
     #[test]
     fn distinct_project_inodes() {
-        assert_ne!(inode_for_project("AAA"), inode_for_project("BBB"));
+        let alloc = InodeAllocator::new(None);
+        let a = alloc.alloc(NodeKey::Project("AAA".to_string()));
+        let b = alloc.alloc(NodeKey::Project("BBB".to_string()));
+        assert_ne!(a, b);
     }
 
     #[test]
     fn issue_inode_is_deterministic_and_namespaced() {
-        let a = inode_for_issue("PROJ", "PROJ-1");
-        let b = inode_for_issue("PROJ", "PROJ-1");
-        let c = inode_for_issue("PROJ", "PROJ-2");
+        let alloc = InodeAllocator::new(None);
+        let a = alloc.alloc(NodeKey::Issue("PROJ".to_string(), "PROJ-1".to_string(), IssueFileKind::Main));
+        let b = alloc.alloc(NodeKey::Issue("PROJ".to_string(), "PROJ-1".to_string(), IssueFileKind::Main));
+        let c = alloc.alloc(NodeKey::Issue("PROJ".to_string(), "PROJ-2".to_string(), IssueFileKind::Main));
+        let project = alloc.alloc(NodeKey::Project("PROJ".to_string()));
         assert_eq!(a, b);
         assert_ne!(a, c);
-        assert_ne!(a, inode_for_project("PROJ"));
+        assert_ne!(a, project);
+    }
+
+    #[test]
+    fn journal_replay_restores_mappings_and_counter() {
+        let journal_path = unique_journal_path();
+
+        let first = InodeAllocator::new(Some(journal_path.clone()));
+        let project_ino = first.alloc(NodeKey::Project("PROJ".to_string()));
+        let issue_ino = first.alloc(NodeKey::Issue(
+            "PROJ".to_string(),
+            "PROJ-1".to_string(),
+            IssueFileKind::Main,
+        ));
+
+        let second = InodeAllocator::new(Some(journal_path.clone()));
+        assert_eq!(second.alloc(NodeKey::Project("PROJ".to_string())), project_ino);
+        assert_eq!(
+            second.alloc(NodeKey::Issue(
+                "PROJ".to_string(),
+                "PROJ-1".to_string(),
+                IssueFileKind::Main
+            )),
+            issue_ino
+        );
+
+        let fresh_ino = second.alloc(NodeKey::Project("OTHER".to_string()));
+        assert_ne!(fresh_ino, project_ino);
+        assert_ne!(fresh_ino, issue_ino);
+
+        let _ = std::fs::remove_file(&journal_path);
+    }
+
+    #[test]
+    fn issue_history_inodes_are_namespaced_per_issue_and_version() {
+        let alloc = InodeAllocator::new(None);
+        let history_a = alloc.alloc(NodeKey::IssueHistory("PROJ-1".to_string()));
+        let history_b = alloc.alloc(NodeKey::IssueHistory("PROJ-1".to_string()));
+        let history_other = alloc.alloc(NodeKey::IssueHistory("PROJ-2".to_string()));
+        assert_eq!(history_a, history_b);
+        assert_ne!(history_a, history_other);
+
+        let version_a = alloc.alloc(NodeKey::IssueVersion(
+            "PROJ-1".to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        ));
+        let version_b = alloc.alloc(NodeKey::IssueVersion(
+            "PROJ-1".to_string(),
+            "2026-01-02T00:00:00Z".to_string(),
+        ));
+        assert_ne!(version_a, version_b);
+        assert_ne!(version_a, history_a);
+    }
+
+    #[test]
+    fn issue_history_journal_replay_restores_mappings() {
+        let journal_path = unique_journal_path();
+
+        let first = InodeAllocator::new(Some(journal_path.clone()));
+        let history_ino = first.alloc(NodeKey::IssueHistory("PROJ-1".to_string()));
+        let version_ino = first.alloc(NodeKey::IssueVersion(
+            "PROJ-1".to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        ));
+
+        let second = InodeAllocator::new(Some(journal_path.clone()));
+        assert_eq!(
+            second.alloc(NodeKey::IssueHistory("PROJ-1".to_string())),
+            history_ino
+        );
+        assert_eq!(
+            second.alloc(NodeKey::IssueVersion(
+                "PROJ-1".to_string(),
+                "2026-01-01T00:00:00Z".to_string()
+            )),
+            version_ino
+        );
+
+        let _ = std::fs::remove_file(&journal_path);
     }
 }
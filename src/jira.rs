@@ -0,0 +1,323 @@
+use serde_json::Value;
+
+/// The two fields Jira's `/search` response is read for: the issue key and
+/// its `updated` timestamp. Everything downstream (`render`, the cache's
+/// staleness checks) keys off just these two.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IssueRef {
+    pub key: String,
+    pub updated: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum JiraError {
+    /// The request itself never got a response (DNS, connect, timeout, TLS).
+    Transport(String),
+    /// Jira answered, but not with 2xx.
+    Api { status: u16, message: String },
+    /// The response body wasn't the JSON shape this client expects.
+    UnexpectedResponse(String),
+}
+
+impl std::fmt::Display for JiraError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JiraError::Transport(msg) => write!(f, "jira request failed: {}", msg),
+            JiraError::Api { status, message } => {
+                write!(f, "jira api error ({}): {}", status, message)
+            }
+            JiraError::UnexpectedResponse(msg) => {
+                write!(f, "unexpected jira response: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for JiraError {}
+
+/// Talks to the Jira Cloud REST API (`/rest/api/2`) over HTTPS, authenticating
+/// with an account email + API token via HTTP Basic auth — the standard
+/// Atlassian Cloud scheme. Every method is a single blocking request; there's
+/// no connection pooling or retry here, matching the rest of this crate's
+/// synchronous, worker-thread-driven design (see `scrub`, `periodic_sync`).
+pub struct JiraClient {
+    base_url: String,
+    email: String,
+    api_token: String,
+    agent: ureq::Agent,
+}
+
+impl JiraClient {
+    pub fn new(base_url: impl Into<String>, email: impl Into<String>, api_token: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            email: email.into(),
+            api_token: api_token.into(),
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    fn basic_auth_header(&self) -> String {
+        let credentials = format!("{}:{}", self.email, self.api_token);
+        format!("Basic {}", base64_encode(credentials.as_bytes()))
+    }
+
+    fn search(&self, jql: &str, max_results: usize, start_at: usize) -> Result<Vec<IssueRef>, JiraError> {
+        let url = format!("{}/rest/api/2/search", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Authorization", &self.basic_auth_header())
+            .set("Accept", "application/json")
+            .query("jql", jql)
+            .query("maxResults", &max_results.to_string())
+            .query("startAt", &start_at.to_string())
+            .query("fields", "updated")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => JiraError::Api {
+                    status,
+                    message: response
+                        .into_string()
+                        .unwrap_or_else(|_| "<unreadable body>".to_string()),
+                },
+                ureq::Error::Transport(transport) => JiraError::Transport(transport.to_string()),
+            })?;
+
+        let body: Value = response
+            .into_json()
+            .map_err(|err| JiraError::UnexpectedResponse(err.to_string()))?;
+
+        let issues = body
+            .get("issues")
+            .and_then(Value::as_array)
+            .ok_or_else(|| JiraError::UnexpectedResponse("missing \"issues\" array".to_string()))?;
+
+        issues
+            .iter()
+            .map(|issue| {
+                let key = issue
+                    .get("key")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| JiraError::UnexpectedResponse("issue missing \"key\"".to_string()))?
+                    .to_string();
+                let updated = issue
+                    .get("fields")
+                    .and_then(|fields| fields.get("updated"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                Ok(IssueRef { key, updated })
+            })
+            .collect()
+    }
+
+    /// Fetches every issue matching `jql`, requesting successive pages of 100
+    /// until Jira returns a short page. Used by `fs` and by the whole-scope
+    /// scans in `warmup::reconcile_projects` and `warmup::probe_pending_count`.
+    pub fn list_issue_refs_for_jql(&self, jql: &str) -> Result<Vec<IssueRef>, JiraError> {
+        let mut refs = Vec::new();
+        loop {
+            let page = self.search(jql, 100, refs.len())?;
+            let page_len = page.len();
+            refs.extend(page);
+            if page_len < 100 {
+                return Ok(refs);
+            }
+        }
+    }
+
+    /// Fetches up to `max_results` issues matching `jql`, starting from the
+    /// first page. Used for small, targeted lookups (e.g. `key = PROJ-1`).
+    pub fn search_issues_bulk(&self, jql: &str, max_results: usize) -> Result<Vec<IssueRef>, JiraError> {
+        self.search(jql, max_results, 0)
+    }
+
+    /// Fetches one page of up to `page_size` issues matching `jql`, starting
+    /// at `start_at` — the continuation primitive `warmup::sync_issues_resumable`
+    /// drives to page through a whole workspace.
+    pub fn search_issues_bulk_from(
+        &self,
+        jql: &str,
+        page_size: usize,
+        start_at: usize,
+    ) -> Result<Vec<IssueRef>, JiraError> {
+        self.search(jql, page_size, start_at)
+    }
+
+    /// Looks up the transition id Jira exposes for moving `issue_key` into
+    /// `status_name`, or `None` if no such transition is currently available
+    /// (the status name is unrecognized, or the issue's workflow doesn't
+    /// offer it from its current state).
+    pub fn find_transition_id(&self, issue_key: &str, status_name: &str) -> Result<Option<String>, JiraError> {
+        let url = format!("{}/rest/api/2/issue/{}/transitions", self.base_url, issue_key);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Authorization", &self.basic_auth_header())
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => JiraError::Api {
+                    status,
+                    message: response
+                        .into_string()
+                        .unwrap_or_else(|_| "<unreadable body>".to_string()),
+                },
+                ureq::Error::Transport(transport) => JiraError::Transport(transport.to_string()),
+            })?;
+
+        let body: Value = response
+            .into_json()
+            .map_err(|err| JiraError::UnexpectedResponse(err.to_string()))?;
+
+        let transitions = body
+            .get("transitions")
+            .and_then(Value::as_array)
+            .ok_or_else(|| JiraError::UnexpectedResponse("missing \"transitions\" array".to_string()))?;
+
+        for transition in transitions {
+            let name_matches = transition
+                .get("to")
+                .and_then(|to| to.get("name"))
+                .and_then(Value::as_str)
+                .map(|name| name.eq_ignore_ascii_case(status_name))
+                .unwrap_or(false);
+            if name_matches {
+                let id = transition
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| JiraError::UnexpectedResponse("transition missing \"id\"".to_string()))?;
+                return Ok(Some(id.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Executes a previously looked-up transition id on `issue_key`.
+    pub fn transition_issue(&self, issue_key: &str, transition_id: &str) -> Result<(), JiraError> {
+        let url = format!("{}/rest/api/2/issue/{}/transitions", self.base_url, issue_key);
+        self.agent
+            .post(&url)
+            .set("Authorization", &self.basic_auth_header())
+            .set("Content-Type", "application/json")
+            .send_json(serde_json::json!({ "transition": { "id": transition_id } }))
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => JiraError::Api {
+                    status,
+                    message: response
+                        .into_string()
+                        .unwrap_or_else(|_| "<unreadable body>".to_string()),
+                },
+                ureq::Error::Transport(transport) => JiraError::Transport(transport.to_string()),
+            })?;
+        Ok(())
+    }
+
+    /// Sets a single plain-text/string field (`summary`, `assignee`,
+    /// `priority`, ...) on `issue_key`.
+    pub fn update_issue_field(&self, issue_key: &str, field: &str, value: &str) -> Result<(), JiraError> {
+        let url = format!("{}/rest/api/2/issue/{}", self.base_url, issue_key);
+        let mut fields = serde_json::Map::new();
+        fields.insert(field.to_string(), Value::String(value.to_string()));
+        self.agent
+            .put(&url)
+            .set("Authorization", &self.basic_auth_header())
+            .set("Content-Type", "application/json")
+            .send_json(serde_json::json!({ "fields": fields }))
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => JiraError::Api {
+                    status,
+                    message: response
+                        .into_string()
+                        .unwrap_or_else(|_| "<unreadable body>".to_string()),
+                },
+                ureq::Error::Transport(transport) => JiraError::Transport(transport.to_string()),
+            })?;
+        Ok(())
+    }
+
+    /// Replaces `issue_key`'s label set wholesale.
+    pub fn update_issue_labels(&self, issue_key: &str, labels: &[String]) -> Result<(), JiraError> {
+        let url = format!("{}/rest/api/2/issue/{}", self.base_url, issue_key);
+        self.agent
+            .put(&url)
+            .set("Authorization", &self.basic_auth_header())
+            .set("Content-Type", "application/json")
+            .send_json(serde_json::json!({ "fields": { "labels": labels } }))
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => JiraError::Api {
+                    status,
+                    message: response
+                        .into_string()
+                        .unwrap_or_else(|_| "<unreadable body>".to_string()),
+                },
+                ureq::Error::Transport(transport) => JiraError::Transport(transport.to_string()),
+            })?;
+        Ok(())
+    }
+
+    /// Posts a new top-level comment to `issue_key`.
+    pub fn add_comment(&self, issue_key: &str, body: &str) -> Result<(), JiraError> {
+        let url = format!("{}/rest/api/2/issue/{}/comment", self.base_url, issue_key);
+        self.agent
+            .post(&url)
+            .set("Authorization", &self.basic_auth_header())
+            .set("Content-Type", "application/json")
+            .send_json(serde_json::json!({ "body": body }))
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => JiraError::Api {
+                    status,
+                    message: response
+                        .into_string()
+                        .unwrap_or_else(|_| "<unreadable body>".to_string()),
+                },
+                ureq::Error::Transport(transport) => JiraError::Transport(transport.to_string()),
+            })?;
+        Ok(())
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 encoding (with `=` padding) for the HTTP Basic auth
+/// header — the only place this crate needs it, so it's inlined here rather
+/// than pulling in a dedicated dependency.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char);
+        if let Some(b1) = b1 {
+            out.push(BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char);
+        } else {
+            out.push('=');
+        }
+        if let Some(b2) = b2 {
+            out.push(BASE64_ALPHABET[(b2 & 0x3f) as usize] as char);
+        } else {
+            out.push('=');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_encodes_rfc4648_test_vectors() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+        assert_eq!(base64_encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+}
@@ -0,0 +1,14 @@
+//! Minimal leveled logging used throughout the crate in place of a full
+//! logging framework — `info` for routine sync/worker activity, `warn` for
+//! recoverable failures (a failed fetch, a poisoned mutex, a discarded
+//! checkpoint) that shouldn't take the mount down.
+
+/// Logs a routine, expected event (sync progress, a worker starting, etc.).
+pub fn info(message: impl AsRef<str>) {
+    eprintln!("[fs-jira] INFO {}", message.as_ref());
+}
+
+/// Logs a recoverable failure that the caller is choosing to continue past.
+pub fn warn(message: impl AsRef<str>) {
+    eprintln!("[fs-jira] WARN {}", message.as_ref());
+}
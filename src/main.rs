@@ -1,171 +1,85 @@
-use std::ffi::OsStr;
-use std::time::{Duration, UNIX_EPOCH};
-
-use fuser::{
-    Config, Errno, FileAttr, FileHandle, FileType, Filesystem, FopenFlags, Generation, INodeNo,
-    MountOption, OpenAccMode, OpenFlags, ReplyAttr, ReplyData, ReplyDirectory, ReplyEntry,
-    ReplyOpen, Request,
-};
-
-const ROOT_INO: INodeNo = INodeNo::ROOT;
-const TEST_INO: INodeNo = INodeNo(2);
-const TEST_NAME: &str = "test.md";
-const TEST_CONTENT: &[u8] = b"Hello World!\n";
-const TTL: Duration = Duration::from_secs(1);
-
-struct BootstrapFs {
-    uid: u32,
-    gid: u32,
-}
-
-impl BootstrapFs {
-    fn root_attr(&self) -> FileAttr {
-        FileAttr {
-            ino: ROOT_INO,
-            size: 0,
-            blocks: 0,
-            atime: UNIX_EPOCH,
-            mtime: UNIX_EPOCH,
-            ctime: UNIX_EPOCH,
-            crtime: UNIX_EPOCH,
-            kind: FileType::Directory,
-            perm: 0o555,
-            nlink: 2,
-            uid: self.uid,
-            gid: self.gid,
-            rdev: 0,
-            flags: 0,
-            blksize: 512,
-        }
-    }
-
-    fn test_attr(&self) -> FileAttr {
-        FileAttr {
-            ino: TEST_INO,
-            size: TEST_CONTENT.len() as u64,
-            blocks: 1,
-            atime: UNIX_EPOCH,
-            mtime: UNIX_EPOCH,
-            ctime: UNIX_EPOCH,
-            crtime: UNIX_EPOCH,
-            kind: FileType::RegularFile,
-            perm: 0o444,
-            nlink: 1,
-            uid: self.uid,
-            gid: self.gid,
-            rdev: 0,
-            flags: 0,
-            blksize: 512,
-        }
-    }
-}
-
-impl Filesystem for BootstrapFs {
-    fn lookup(&self, _req: &Request, parent: INodeNo, name: &OsStr, reply: ReplyEntry) {
-        if parent == ROOT_INO && name == OsStr::new(TEST_NAME) {
-            reply.entry(&TTL, &self.test_attr(), Generation(0));
-            return;
-        }
-
-        reply.error(Errno::ENOENT);
-    }
-
-    fn getattr(&self, _req: &Request, ino: INodeNo, _fh: Option<FileHandle>, reply: ReplyAttr) {
-        match ino {
-            ROOT_INO => reply.attr(&TTL, &self.root_attr()),
-            TEST_INO => reply.attr(&TTL, &self.test_attr()),
-            _ => reply.error(Errno::ENOENT),
-        }
-    }
-
-    fn readdir(
-        &self,
-        _req: &Request,
-        ino: INodeNo,
-        _fh: FileHandle,
-        offset: u64,
-        mut reply: ReplyDirectory,
-    ) {
-        if ino != ROOT_INO {
-            reply.error(Errno::ENOENT);
-            return;
-        }
-
-        let entries = [
-            (ROOT_INO, FileType::Directory, "."),
-            (ROOT_INO, FileType::Directory, ".."),
-            (TEST_INO, FileType::RegularFile, TEST_NAME),
-        ];
-
-        for (idx, (entry_ino, kind, name)) in entries.iter().enumerate().skip(offset as usize) {
-            let next_offset = (idx + 1) as u64;
-            if reply.add(*entry_ino, next_offset, *kind, name) {
-                break;
-            }
-        }
-
-        reply.ok();
-    }
-
-    fn open(&self, _req: &Request, ino: INodeNo, flags: OpenFlags, reply: ReplyOpen) {
-        if ino != TEST_INO {
-            reply.error(Errno::ENOENT);
-            return;
-        }
-
-        if flags.acc_mode() != OpenAccMode::O_RDONLY {
-            reply.error(Errno::EROFS);
-            return;
-        }
-
-        reply.opened(FileHandle(0), FopenFlags::empty());
-    }
-
-    fn read(
-        &self,
-        _req: &Request,
-        ino: INodeNo,
-        _fh: FileHandle,
-        offset: u64,
-        size: u32,
-        _flags: OpenFlags,
-        _lock_owner: Option<fuser::LockOwner>,
-        reply: ReplyData,
-    ) {
-        if ino != TEST_INO {
-            reply.error(Errno::ENOENT);
-            return;
-        }
-
-        let start = offset as usize;
-        if start >= TEST_CONTENT.len() {
-            reply.data(&[]);
-            return;
-        }
-
-        let end = start.saturating_add(size as usize).min(TEST_CONTENT.len());
-        reply.data(&TEST_CONTENT[start..end]);
-    }
-}
+use std::sync::Arc;
+use std::time::Duration;
+
+use fuser::{Config, MountOption};
+
+use crate::cache::InMemoryCache;
+use crate::fs::JiraFuseFs;
+use crate::jira::JiraClient;
+use crate::metrics::Metrics;
+use crate::sync_state::SyncState;
+
+mod cache;
+mod fs;
+mod jira;
+mod logging;
+mod metrics;
+mod periodic_sync;
+mod render;
+mod scrub;
+mod sync_state;
+mod warmup;
+mod workers;
+mod writeback;
+
+/// How often the periodic sync worker re-syncs projects in the background;
+/// see [`SyncState::new`].
+const DEFAULT_SYNC_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// How long a project's issue list is trusted before a fresh fetch is
+/// required; see [`InMemoryCache::new`].
+const DEFAULT_PROJECT_TTL: Duration = Duration::from_secs(60);
+
+/// How long a single issue's rendered markdown is trusted before a fresh
+/// fetch is required; see [`InMemoryCache::new`].
+const DEFAULT_ISSUE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Max issues fetched per sync pass; see [`warmup::sync_issues_resumable`].
+const DEFAULT_SYNC_BUDGET: usize = 200;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut args = std::env::args_os();
     let _program = args.next();
+    let usage = "usage: cargo run -- <mountpoint> <project,project,...>";
     let mountpoint = match args.next() {
         Some(path) => path,
-        None => {
-            return Err("usage: cargo run -- <mountpoint>".into());
-        }
+        None => return Err(usage.into()),
     };
-
-    let fs = BootstrapFs {
-        uid: unsafe { libc::geteuid() },
-        gid: unsafe { libc::getegid() },
+    let projects: Vec<String> = match args.next() {
+        Some(list) => list
+            .to_string_lossy()
+            .split(',')
+            .map(|project| project.trim().to_string())
+            .filter(|project| !project.is_empty())
+            .collect(),
+        None => return Err(usage.into()),
     };
+    if projects.is_empty() {
+        return Err(usage.into());
+    }
+
+    let metrics = Arc::new(Metrics::new());
+    let cache = Arc::new(InMemoryCache::new(
+        DEFAULT_PROJECT_TTL,
+        DEFAULT_ISSUE_TTL,
+        None,
+        metrics,
+    ));
+    let sync_state = Arc::new(SyncState::new(DEFAULT_SYNC_INTERVAL, None));
+    let jira = build_jira_client()?;
+
+    let fs = JiraFuseFs::new(
+        unsafe { libc::geteuid() },
+        unsafe { libc::getegid() },
+        projects,
+        jira,
+        cache,
+        DEFAULT_SYNC_BUDGET,
+        sync_state,
+    );
 
     let mut config = Config::default();
     config.mount_options.extend([
-        MountOption::RO,
         MountOption::FSName("fs-jira".to_string()),
         MountOption::DefaultPermissions,
     ]);
@@ -173,3 +87,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     fuser::mount2(fs, mountpoint, &config)?;
     Ok(())
 }
+
+/// Builds the `JiraClient` the mount talks to, reading the Cloud API
+/// connection details (account email + API token, per Atlassian's Basic
+/// auth scheme — see `jira::JiraClient::new`) from the environment, since no
+/// other config mechanism exists anywhere in this crate.
+fn build_jira_client() -> Result<Arc<JiraClient>, Box<dyn std::error::Error>> {
+    let base_url = require_env("JIRA_BASE_URL")?;
+    let email = require_env("JIRA_EMAIL")?;
+    let api_token = require_env("JIRA_API_TOKEN")?;
+    Ok(Arc::new(JiraClient::new(base_url, email, api_token)))
+}
+
+fn require_env(name: &str) -> Result<String, Box<dyn std::error::Error>> {
+    std::env::var(name).map_err(|_| format!("missing required environment variable {}", name).into())
+}
@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Process-wide counters and gauges for the cache and persistence layers.
+/// Cheap to clone around as `Arc<Metrics>`; every update is a relaxed atomic
+/// op, so this never contends with the mutexes it's sitting next to.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    stale_served: AtomicU64,
+    issue_cache_evictions: AtomicU64,
+    resident_issue_bytes: AtomicU64,
+    compression_level: AtomicU64,
+    read_pool_size: AtomicU64,
+    read_pool_checkout_wait_micros: AtomicU64,
+    compressed_bytes: AtomicU64,
+    uncompressed_bytes: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn inc_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_cache_miss(&self) {
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_stale_served(&self) {
+        self.stale_served.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_issue_cache_eviction(&self) {
+        self.issue_cache_evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn set_resident_issue_bytes(&self, bytes: u64) {
+        self.resident_issue_bytes.store(bytes, Ordering::Relaxed);
+    }
+
+    pub fn set_compression_level(&self, level: i32) {
+        self.compression_level.store(level as u64, Ordering::Relaxed);
+    }
+
+    pub fn set_read_pool_size(&self, size: u32) {
+        self.read_pool_size.store(size as u64, Ordering::Relaxed);
+    }
+
+    pub fn observe_read_pool_checkout_wait(&self, wait: Duration) {
+        self.read_pool_checkout_wait_micros
+            .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub fn observe_compression_ratio(&self, uncompressed_bytes: u64, compressed_bytes: u64) {
+        self.uncompressed_bytes
+            .fetch_add(uncompressed_bytes, Ordering::Relaxed);
+        self.compressed_bytes
+            .fetch_add(compressed_bytes, Ordering::Relaxed);
+    }
+}
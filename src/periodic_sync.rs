@@ -0,0 +1,175 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::cache::InMemoryCache;
+use crate::jira::JiraClient;
+use crate::logging;
+use crate::sync_state::SyncState;
+use crate::warmup::sync_issues_resumable;
+use crate::workers::{WorkerCommand, WorkerManager, WorkerState};
+
+/// How often the loop polls `SyncState` for a due or manually triggered
+/// sync, between actual sync attempts.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Live counters for the periodic-sync worker, surfaced via
+/// `.sync_meta/sync_status` and `.sync_meta/sync_tranquility`.
+#[derive(Debug)]
+pub struct PeriodicSyncStatus {
+    tranquility: AtomicU64,
+    synced: AtomicUsize,
+    skipped: AtomicUsize,
+    state: std::sync::Mutex<&'static str>,
+}
+
+impl PeriodicSyncStatus {
+    fn new(tranquility: u64) -> Self {
+        Self {
+            tranquility: AtomicU64::new(tranquility),
+            synced: AtomicUsize::new(0),
+            skipped: AtomicUsize::new(0),
+            state: std::sync::Mutex::new("idle"),
+        }
+    }
+
+    pub fn tranquility(&self) -> u64 {
+        self.tranquility.load(Ordering::Relaxed)
+    }
+
+    pub fn set_tranquility(&self, value: u64) {
+        self.tranquility.store(value, Ordering::Relaxed);
+    }
+
+    fn set_state(&self, state: &'static str) {
+        if let Ok(mut guard) = self.state.lock() {
+            *guard = state;
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let state = self.state.lock().map(|g| *g).unwrap_or("unknown");
+        serde_json::json!({
+            "state": state,
+            "synced": self.synced.load(Ordering::Relaxed),
+            "skipped": self.skipped.load(Ordering::Relaxed),
+            "tranquility": self.tranquility(),
+        })
+    }
+}
+
+/// Spawns the background worker that drives `SyncState`'s scheduling: it
+/// wakes on the configured interval or a manual trigger, runs a resumable
+/// sync, and reports Active/Idle/Dead plus progress and the last error
+/// through the same `WorkerManager` surface `.sync_meta/workers/` already
+/// renders, instead of the interval/manual-trigger fields sitting unread.
+/// Throttled by `tranquility`: after a sync iteration of duration `d`, the
+/// worker sleeps `d * tranquility` before checking whether another sync is
+/// due, the same backoff `scrub.rs` uses for its revalidation loop.
+pub fn spawn_periodic_sync_worker(
+    jira: Arc<JiraClient>,
+    cache: Arc<InMemoryCache>,
+    projects: Vec<String>,
+    sync_budget: usize,
+    sync_state: Arc<SyncState>,
+    workers: &WorkerManager,
+    default_tranquility: u64,
+) -> Arc<PeriodicSyncStatus> {
+    let status = Arc::new(PeriodicSyncStatus::new(default_tranquility));
+    let (worker, commands) = workers.register("periodic_sync");
+
+    let status_for_thread = Arc::clone(&status);
+    std::thread::spawn(move || {
+        let status = status_for_thread;
+        'outer: loop {
+            loop {
+                match commands.try_recv() {
+                    Ok(WorkerCommand::Cancel) => {
+                        worker.set_state(WorkerState::Dead);
+                        status.set_state("cancelled");
+                        break 'outer;
+                    }
+                    Ok(WorkerCommand::Pause) => {
+                        worker.set_state(WorkerState::Idle);
+                        status.set_state("paused");
+                        std::thread::sleep(POLL_INTERVAL);
+                        continue;
+                    }
+                    Ok(WorkerCommand::Resume) | Err(_) => break,
+                }
+            }
+
+            let force_full = sync_state.check_and_clear_manual_full_trigger();
+            let manual = sync_state.check_and_clear_manual_trigger();
+            let due = force_full || manual || sync_state.seconds_until_next_sync() == 0;
+
+            if !due || !sync_state.mark_sync_start() {
+                std::thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+
+            worker.set_state(WorkerState::Active);
+            worker.set_progress(0, projects.len());
+            status.set_state("syncing");
+            let started = Instant::now();
+
+            let resume_from = cache
+                .get_sync_checkpoint()
+                .filter(|checkpoint| projects.iter().any(|p| p == &checkpoint.project));
+            if let Some(checkpoint) = &resume_from {
+                logging::info(format!(
+                    "resuming periodic sync from persisted checkpoint for {}",
+                    checkpoint.project
+                ));
+            } else {
+                logging::info("periodic sync starting...");
+            }
+
+            let sync_result = sync_issues_resumable(
+                &jira,
+                &cache,
+                &projects,
+                sync_budget,
+                force_full,
+                resume_from,
+            );
+
+            sync_state.mark_sync_complete();
+            if force_full {
+                sync_state.mark_full_sync_complete();
+            }
+            sync_state.mark_sync_end();
+
+            logging::info(format!(
+                "periodic sync complete: cached={} skipped={} errors={}",
+                sync_result.issues_cached,
+                sync_result.issues_skipped,
+                sync_result.errors.len()
+            ));
+
+            status.synced.fetch_add(sync_result.issues_cached, Ordering::Relaxed);
+            status.skipped.fetch_add(sync_result.issues_skipped, Ordering::Relaxed);
+            worker.set_progress(projects.len(), projects.len());
+            if sync_result.errors.is_empty() {
+                worker.set_last_error(None);
+            } else {
+                worker.set_last_error(sync_result.errors.last().cloned());
+                for err in &sync_result.errors {
+                    logging::warn(format!("periodic sync error: {}", err));
+                }
+            }
+
+            let elapsed = started.elapsed();
+            let tranquility = status.tranquility();
+            worker.set_state(WorkerState::Idle);
+            status.set_state("idle");
+            if tranquility > 0 {
+                std::thread::sleep(elapsed * tranquility as u32);
+            } else {
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        }
+    });
+
+    status
+}
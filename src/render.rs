@@ -0,0 +1,22 @@
+use crate::jira::IssueRef;
+
+/// Renders an issue's `.md` file: a `---`-delimited front-matter block (see
+/// `writeback::IssueFrontMatter`/`parse_front_matter`) followed by the body.
+/// `IssueRef` only carries the fields Jira's search API echoes back (`key`,
+/// `updated`), so `summary` is filled with the issue key and everything else
+/// `IssueFrontMatter` knows how to parse back out (`assignee`, `labels`,
+/// `status`, `priority`) is left blank here rather than guessed at.
+pub fn render_issue_markdown(issue: &IssueRef) -> String {
+    format!(
+        "---\nsummary: {}\nstatus:\nassignee:\npriority:\nlabels: []\n---\n\nupdated: {}\n",
+        issue.key,
+        issue.updated.as_deref().unwrap_or("unknown"),
+    )
+}
+
+/// Renders an issue's `comments.md` sidecar. `IssueRef` doesn't carry
+/// comment bodies, so this is a placeholder until a richer issue fetch is
+/// wired in.
+pub fn render_issue_comments_markdown(issue: &IssueRef) -> String {
+    format!("# Comments for {}\n\n(no comments synced)\n", issue.key)
+}
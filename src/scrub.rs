@@ -0,0 +1,204 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::cache::InMemoryCache;
+use crate::jira::JiraClient;
+use crate::logging;
+use crate::render::{render_issue_comments_markdown, render_issue_markdown};
+use crate::sync_state::SyncState;
+use crate::workers::{WorkerCommand, WorkerManager, WorkerState};
+
+/// Live counters for the scrub worker, surfaced via `.sync_meta/scrub_status`
+/// and `.sync_meta/scrub_tranquility`.
+#[derive(Debug)]
+pub struct ScrubStatus {
+    tranquility: AtomicU64,
+    checked: AtomicUsize,
+    refreshed: AtomicUsize,
+    deleted: AtomicUsize,
+    last_run_unix_secs: AtomicU64,
+    state: std::sync::Mutex<&'static str>,
+}
+
+impl ScrubStatus {
+    fn new(tranquility: u64) -> Self {
+        Self {
+            tranquility: AtomicU64::new(tranquility),
+            checked: AtomicUsize::new(0),
+            refreshed: AtomicUsize::new(0),
+            deleted: AtomicUsize::new(0),
+            last_run_unix_secs: AtomicU64::new(0),
+            state: std::sync::Mutex::new("idle"),
+        }
+    }
+
+    pub fn tranquility(&self) -> u64 {
+        self.tranquility.load(Ordering::Relaxed)
+    }
+
+    pub fn set_tranquility(&self, value: u64) {
+        self.tranquility.store(value, Ordering::Relaxed);
+    }
+
+    fn set_state(&self, state: &'static str) {
+        if let Ok(mut guard) = self.state.lock() {
+            *guard = state;
+        }
+    }
+
+    fn mark_pass_complete(&self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.last_run_unix_secs.store(now, Ordering::Relaxed);
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let state = self.state.lock().map(|g| *g).unwrap_or("unknown");
+        let refreshed = self.refreshed.load(Ordering::Relaxed);
+        let deleted = self.deleted.load(Ordering::Relaxed);
+        serde_json::json!({
+            "state": state,
+            "checked": self.checked.load(Ordering::Relaxed),
+            "refreshed": refreshed,
+            "deleted": deleted,
+            "fixed": refreshed + deleted,
+            "last_run_unix_secs": self.last_run_unix_secs.load(Ordering::Relaxed),
+            "tranquility": self.tranquility(),
+        })
+    }
+}
+
+/// Spawns the long-running reconcile/scrub worker that slowly re-validates
+/// every cached issue against Jira, repairing content drift and evicting
+/// issues Jira no longer returns, throttled by `tranquility`. Claims
+/// `SyncState`'s in-progress flag per issue so a reconcile pass and a normal
+/// sync never run concurrently, and honors a manual trigger (mirroring
+/// `trigger_manual_full`) that skips the tranquility backoff after the next
+/// issue it checks.
+pub fn spawn_scrub_worker(
+    jira: Arc<JiraClient>,
+    cache: Arc<InMemoryCache>,
+    projects: Vec<String>,
+    sync_state: Arc<SyncState>,
+    workers: &WorkerManager,
+    default_tranquility: u64,
+) -> Arc<ScrubStatus> {
+    let status = Arc::new(ScrubStatus::new(default_tranquility));
+    let (worker, commands) = workers.register("scrub");
+
+    let status_for_thread = Arc::clone(&status);
+    std::thread::spawn(move || {
+        let status = status_for_thread;
+        'outer: loop {
+            for project in &projects {
+                let Some(snapshot) = cache.get_project_issues_snapshot(project) else {
+                    continue;
+                };
+
+                for issue in snapshot.issues {
+                    loop {
+                        match commands.try_recv() {
+                            Ok(WorkerCommand::Cancel) => {
+                                worker.set_state(WorkerState::Dead);
+                                status.set_state("cancelled");
+                                break 'outer;
+                            }
+                            Ok(WorkerCommand::Pause) => {
+                                worker.set_state(WorkerState::Idle);
+                                status.set_state("paused");
+                                std::thread::sleep(Duration::from_millis(200));
+                                continue;
+                            }
+                            Ok(WorkerCommand::Resume) | Err(_) => break,
+                        }
+                    }
+
+                    if !sync_state.mark_sync_start() {
+                        // Defer to a user-triggered full refresh instead of
+                        // racing it for the same Jira quota.
+                        std::thread::sleep(Duration::from_millis(200));
+                        continue;
+                    }
+
+                    worker.set_state(WorkerState::Active);
+                    status.set_state("scrubbing");
+                    let started = Instant::now();
+
+                    let jql = format!("key = {}", issue.key);
+                    match jira.search_issues_bulk(&jql, 1) {
+                        Ok(refreshed_issues) => {
+                            status.checked.fetch_add(1, Ordering::Relaxed);
+                            match refreshed_issues.first() {
+                                Some(fresh) => {
+                                    if fresh.updated != issue.updated {
+                                        if let Some(previous) =
+                                            cache.current_issue_markdown(&fresh.key)
+                                        {
+                                            cache.record_issue_history(&fresh.key, &previous);
+                                        }
+                                        let markdown = render_issue_markdown(fresh).into_bytes();
+                                        cache.upsert_issue_direct(
+                                            &fresh.key,
+                                            &markdown,
+                                            fresh.updated.as_deref(),
+                                        );
+                                        let sidecars = vec![(
+                                            fresh.key.clone(),
+                                            render_issue_comments_markdown(fresh).into_bytes(),
+                                            fresh.updated.clone(),
+                                        )];
+                                        let _ = cache.upsert_issue_sidecars_batch(&sidecars);
+                                        status.refreshed.fetch_add(1, Ordering::Relaxed);
+                                    }
+                                }
+                                None => {
+                                    // Jira no longer returns this issue: deleted or the
+                                    // mount's credentials lost access. Either way, drop
+                                    // it from the local cache instead of serving stale
+                                    // content forever.
+                                    cache.remove_issue_everywhere(project, &issue.key);
+                                    status.deleted.fetch_add(1, Ordering::Relaxed);
+                                    logging::info(format!(
+                                        "scrub worker evicted {} (no longer present in Jira)",
+                                        issue.key
+                                    ));
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            worker.set_last_error(Some(err.to_string()));
+                            logging::warn(format!(
+                                "scrub worker failed to refresh {}: {}",
+                                issue.key, err
+                            ));
+                        }
+                    }
+
+                    sync_state.mark_sync_end();
+
+                    worker.set_progress(
+                        status.checked.load(Ordering::Relaxed),
+                        status.checked.load(Ordering::Relaxed)
+                            + status.refreshed.load(Ordering::Relaxed),
+                    );
+
+                    let elapsed = started.elapsed();
+                    let tranquility = status.tranquility();
+                    let skip_backoff = sync_state.check_and_clear_manual_reconcile_trigger();
+                    worker.set_state(WorkerState::Idle);
+                    status.set_state("idle");
+                    if tranquility > 0 && !skip_backoff {
+                        std::thread::sleep(elapsed * tranquility as u32);
+                    }
+                }
+            }
+
+            status.mark_pass_complete();
+        }
+    });
+
+    status
+}
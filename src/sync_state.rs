@@ -1,6 +1,9 @@
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::logging;
 
 #[derive(Debug)]
 pub struct SyncState {
@@ -9,24 +12,55 @@ pub struct SyncState {
     sync_interval: Duration,
     manual_trigger: AtomicBool,
     manual_full_trigger: AtomicBool,
+    manual_reconcile_trigger: AtomicBool,
     sync_in_progress: AtomicBool,
+    state_path: Option<PathBuf>,
 }
 
 impl SyncState {
-    pub fn new(sync_interval: Duration) -> Self {
+    /// Creates a fresh `SyncState`, reconciling `last_sync`/`last_full_sync`
+    /// with the wall-clock timestamps persisted at `state_path` (if any) so
+    /// a restart doesn't look like "never synced" and trigger an immediate
+    /// resync. `Instant`s are derived from `now - persisted_elapsed`,
+    /// clamping to zero elapsed when the persisted timestamp is in the
+    /// future (e.g. the system clock moved backwards).
+    pub fn new(sync_interval: Duration, state_path: Option<PathBuf>) -> Self {
+        let persisted = state_path.as_deref().and_then(load_persisted_sync_times);
+
+        let now = Instant::now();
+        let to_instant = |unix_secs: u64| {
+            let elapsed = unix_epoch_seconds_now()
+                .checked_sub(unix_secs)
+                .unwrap_or(0);
+            now.checked_sub(Duration::from_secs(elapsed)).unwrap_or(now)
+        };
+
+        let last_sync = persisted
+            .as_ref()
+            .and_then(|p| p.last_sync)
+            .map(to_instant);
+        let last_full_sync = persisted
+            .as_ref()
+            .and_then(|p| p.last_full_sync)
+            .map(to_instant);
+
         Self {
-            last_sync: Mutex::new(None),
-            last_full_sync: Mutex::new(None),
+            last_sync: Mutex::new(last_sync),
+            last_full_sync: Mutex::new(last_full_sync),
             sync_interval,
             manual_trigger: AtomicBool::new(false),
             manual_full_trigger: AtomicBool::new(false),
+            manual_reconcile_trigger: AtomicBool::new(false),
             sync_in_progress: AtomicBool::new(false),
+            state_path,
         }
     }
 
     pub fn mark_sync_complete(&self) {
         let mut guard = self.last_sync.lock().expect("last_sync mutex poisoned");
         *guard = Some(Instant::now());
+        drop(guard);
+        self.persist();
     }
 
     pub fn last_sync(&self) -> Option<Instant> {
@@ -39,6 +73,8 @@ impl SyncState {
             .lock()
             .expect("last_full_sync mutex poisoned");
         *guard = Some(Instant::now());
+        drop(guard);
+        self.persist();
     }
 
     pub fn last_full_sync(&self) -> Option<Instant> {
@@ -79,6 +115,16 @@ impl SyncState {
         self.manual_full_trigger.swap(false, Ordering::Relaxed)
     }
 
+    /// Requests an immediate reconcile pass, bypassing the scrub worker's
+    /// tranquility backoff for its next iteration.
+    pub fn trigger_manual_reconcile(&self) {
+        self.manual_reconcile_trigger.store(true, Ordering::Relaxed);
+    }
+
+    pub fn check_and_clear_manual_reconcile_trigger(&self) -> bool {
+        self.manual_reconcile_trigger.swap(false, Ordering::Relaxed)
+    }
+
     pub fn sync_interval(&self) -> Duration {
         self.sync_interval
     }
@@ -96,4 +142,121 @@ impl SyncState {
     pub fn is_sync_in_progress(&self) -> bool {
         self.sync_in_progress.load(Ordering::Relaxed)
     }
+
+    /// Writes `last_sync`/`last_full_sync` as unix seconds to `state_path`,
+    /// if configured, so the next process start can reconcile against them.
+    fn persist(&self) {
+        let Some(state_path) = &self.state_path else {
+            return;
+        };
+
+        let now = unix_epoch_seconds_now();
+        let last_sync_secs = self
+            .last_sync()
+            .map(|instant| now.saturating_sub(instant.elapsed().as_secs()));
+        let last_full_sync_secs = self
+            .last_full_sync()
+            .map(|instant| now.saturating_sub(instant.elapsed().as_secs()));
+
+        let mut contents = String::new();
+        if let Some(secs) = last_sync_secs {
+            contents.push_str(&format!("last_sync={}\n", secs));
+        }
+        if let Some(secs) = last_full_sync_secs {
+            contents.push_str(&format!("last_full_sync={}\n", secs));
+        }
+
+        if let Err(err) = std::fs::write(state_path, contents) {
+            logging::warn(format!(
+                "failed to persist sync state to {}: {}",
+                state_path.display(),
+                err
+            ));
+        }
+    }
+}
+
+struct PersistedSyncTimes {
+    last_sync: Option<u64>,
+    last_full_sync: Option<u64>,
+}
+
+fn load_persisted_sync_times(state_path: &std::path::Path) -> Option<PersistedSyncTimes> {
+    let contents = std::fs::read_to_string(state_path).ok()?;
+
+    let mut last_sync = None;
+    let mut last_full_sync = None;
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let Ok(value) = value.trim().parse::<u64>() else {
+            continue;
+        };
+        match key.trim() {
+            "last_sync" => last_sync = Some(value),
+            "last_full_sync" => last_full_sync = Some(value),
+            _ => {}
+        }
+    }
+
+    Some(PersistedSyncTimes {
+        last_sync,
+        last_full_sync,
+    })
+}
+
+fn unix_epoch_seconds_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_state_path() -> PathBuf {
+        static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("fs_jira_sync_state_test_{}_{}", std::process::id(), n))
+    }
+
+    #[test]
+    fn reconciles_persisted_wall_clock_timestamps_on_restart() {
+        let path = unique_state_path();
+        let now = unix_epoch_seconds_now();
+        std::fs::write(&path, format!("last_sync={}\nlast_full_sync={}\n", now - 30, now - 90))
+            .expect("write state file");
+
+        let state = SyncState::new(Duration::from_secs(60), Some(path.clone()));
+
+        assert!(state.last_sync().is_some());
+        assert!(state.seconds_until_next_sync() <= 30);
+        assert!(state.last_full_sync().is_some());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn missing_state_file_behaves_like_never_synced() {
+        let path = unique_state_path();
+        let state = SyncState::new(Duration::from_secs(60), Some(path));
+        assert!(state.last_sync().is_none());
+        assert_eq!(state.seconds_until_next_sync(), 0);
+    }
+
+    #[test]
+    fn mark_sync_complete_persists_and_is_picked_up_on_restart() {
+        let path = unique_state_path();
+        let state = SyncState::new(Duration::from_secs(60), Some(path.clone()));
+        state.mark_sync_complete();
+
+        let reopened = SyncState::new(Duration::from_secs(60), Some(path.clone()));
+        assert!(reopened.last_sync().is_some());
+        assert!(reopened.seconds_until_next_sync() > 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
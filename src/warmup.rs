@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use regex::Regex;
 
-use crate::cache::InMemoryCache;
+use crate::cache::{InMemoryCache, SyncCheckpoint, SyncTaskKind, SyncTaskStatus};
 use crate::jira::JiraClient;
 use crate::logging;
 use crate::render::{render_issue_comments_markdown, render_issue_markdown};
@@ -12,12 +12,17 @@ pub fn seed_workspace_listings(
     cache: &InMemoryCache,
     workspaces: &[(String, String)],
 ) -> usize {
+    let workspace_names: Vec<String> = workspaces.iter().map(|(workspace, _)| workspace.clone()).collect();
+    let task_id = cache.enqueue_sync_task(SyncTaskKind::Full, workspace_names);
+    cache.start_sync_task(task_id);
+
     let mut seeded = 0;
+    let mut errors = Vec::new();
     for (workspace, jql) in workspaces {
         match jira.list_issue_refs_for_jql(jql) {
             Ok(items) => {
                 let count = items.len();
-                cache.upsert_workspace_issues(workspace, items);
+                cache.upsert_project_issues(workspace, items);
                 logging::info(format!(
                     "seeded workspace listing for {} with {} issues",
                     workspace, count
@@ -25,10 +30,22 @@ pub fn seed_workspace_listings(
                 seeded += 1;
             }
             Err(err) => {
-                logging::warn(format!("failed to seed workspace {}: {}", workspace, err));
+                let msg = format!("failed to seed workspace {}: {}", workspace, err);
+                logging::warn(&msg);
+                errors.push(msg);
             }
         }
     }
+
+    let status = if errors.is_empty() {
+        SyncTaskStatus::Succeeded
+    } else if seeded > 0 {
+        SyncTaskStatus::Partial
+    } else {
+        SyncTaskStatus::Failed
+    };
+    cache.finish_sync_task(task_id, status, errors);
+
     seeded
 }
 
@@ -36,6 +53,32 @@ pub struct SyncResult {
     pub issues_cached: usize,
     pub issues_skipped: usize,
     pub errors: Vec<String>,
+    /// Issues evicted by [`reconcile_projects`] because they fell out of
+    /// their workspace's JQL scope. Always `0` for [`sync_issues`],
+    /// [`sync_issues_parallel`], and [`sync_issues_resumable`].
+    pub evicted: usize,
+}
+
+/// Per-request cap on how much of the global `budget` a single
+/// workspace can claim in [`sync_issues_parallel`], so one workspace with a
+/// runaway pending count can't starve the others entirely.
+const PARALLEL_WORKSPACE_BUDGET_CAP: usize = 100;
+
+/// How long a tombstone is kept before [`reconcile_projects`] garbage
+/// collects it via `InMemoryCache::gc_tombstones` — long enough to be
+/// useful to a caller checking recent deletions, short enough that
+/// `project_tombstones` doesn't grow unbounded over a long-lived mount.
+const TOMBSTONE_RETENTION_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// What fetching and caching one workspace's JQL produced, returned by
+/// [`sync_one_workspace`] so both the sequential and parallel sync entry
+/// points can share the same fetch/merge/cache logic.
+struct WorkspaceSyncOutcome {
+    issues_cached: usize,
+    newly_cached_keys: Vec<String>,
+    /// True when the workspace's JQL came back empty (nothing to do).
+    skipped: bool,
+    error: Option<String>,
 }
 
 pub fn sync_issues(
@@ -44,11 +87,160 @@ pub fn sync_issues(
     workspaces: &[(String, String)],
     budget: usize,
     force_full: bool,
+) -> SyncResult {
+    let workspace_names: Vec<String> = workspaces.iter().map(|(workspace, _)| workspace.clone()).collect();
+    let kind = if force_full { SyncTaskKind::Full } else { SyncTaskKind::Incremental };
+    let task_id = cache.enqueue_sync_task(kind, workspace_names);
+    cache.start_sync_task(task_id);
+
+    let result = sync_issues_resumable(jira, cache, workspaces, budget, force_full, None);
+
+    let status = if !result.errors.is_empty() {
+        if result.issues_cached > 0 || result.issues_skipped > 0 {
+            SyncTaskStatus::Partial
+        } else {
+            SyncTaskStatus::Failed
+        }
+    } else {
+        SyncTaskStatus::Succeeded
+    };
+    cache.finish_sync_task(task_id, status, result.errors.clone());
+
+    result
+}
+
+/// Parallel counterpart to [`sync_issues`]: runs every workspace's
+/// [`sync_one_workspace`] on its own worker thread instead of walking
+/// `workspaces` sequentially, so one slow or large workspace can't starve
+/// the others' wall-clock. The global `budget` is apportioned across
+/// workspaces in proportion to each one's pending-change count, measured by
+/// a cheap `list_issue_refs_for_jql` probe issued before any markdown is
+/// fetched, and clamped per workspace by [`PARALLEL_WORKSPACE_BUDGET_CAP`].
+///
+/// `cache`'s mutations are already serialized internally (every field behind
+/// a `Mutex`), so sharing one `Arc<InMemoryCache>` across the worker threads
+/// is sufficient — no extra locking is needed here. Unlike [`sync_issues`],
+/// this mode doesn't persist a resumable checkpoint: a killed run simply
+/// redoes its probe and re-fetches on the next call.
+///
+/// # Errors
+/// Per-workspace failures are collected into `result.errors` rather than
+/// aborting the other workspaces' syncs.
+pub fn sync_issues_parallel(
+    jira: &JiraClient,
+    cache: &Arc<InMemoryCache>,
+    workspaces: &[(String, String)],
+    budget: usize,
+    force_full: bool,
+) -> SyncResult {
+    let mut result = SyncResult {
+        issues_cached: 0,
+        issues_skipped: 0,
+        errors: Vec::new(),
+        evicted: 0,
+    };
+
+    if budget == 0 {
+        return result;
+    }
+
+    if !cache.has_persistence() {
+        result
+            .errors
+            .push("cache.db_path must be configured for sync".to_string());
+        return result;
+    }
+
+    let pending_counts: Vec<usize> = std::thread::scope(|scope| {
+        let handles: Vec<_> = workspaces
+            .iter()
+            .map(|(workspace, base_jql)| {
+                scope.spawn(|| probe_pending_count(jira, cache, workspace, base_jql, force_full))
+            })
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap_or(0)).collect()
+    });
+
+    let total_pending: usize = pending_counts.iter().sum();
+    let workspace_budgets: Vec<usize> = pending_counts
+        .iter()
+        .map(|&pending| {
+            let share = if total_pending == 0 {
+                budget / workspaces.len().max(1)
+            } else {
+                (budget * pending) / total_pending
+            };
+            share.clamp(1, PARALLEL_WORKSPACE_BUDGET_CAP).min(budget)
+        })
+        .collect();
+
+    let outcomes: Vec<(String, WorkspaceSyncOutcome)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = workspaces
+            .iter()
+            .zip(workspace_budgets.iter())
+            .map(|((workspace, base_jql), &workspace_budget)| {
+                scope.spawn(move || {
+                    let outcome =
+                        sync_one_workspace(jira, cache, workspace, base_jql, force_full, workspace_budget, &[]);
+                    (workspace.clone(), outcome)
+                })
+            })
+            .collect();
+        handles.into_iter().filter_map(|handle| handle.join().ok()).collect()
+    });
+
+    for (_workspace, outcome) in outcomes {
+        match outcome.error {
+            Some(error) => result.errors.push(error),
+            None if outcome.skipped => {
+                result.issues_skipped += 1;
+            }
+            None => result.issues_cached += outcome.issues_cached,
+        }
+    }
+
+    result
+}
+
+/// Cheap stand-in for a full fetch: counts how many issues in `workspace`
+/// match its pending-change JQL (the same filter [`sync_one_workspace`]
+/// would use) without rendering or caching any of them. Used to size each
+/// workspace's share of the budget in [`sync_issues_parallel`].
+fn probe_pending_count(
+    jira: &JiraClient,
+    cache: &InMemoryCache,
+    workspace: &str,
+    base_jql: &str,
+    force_full: bool,
+) -> usize {
+    let (base_filter, _) = split_jql_order_by(base_jql);
+    let cursor = if force_full { None } else { cache.get_sync_cursor(workspace) };
+    let probe_jql = match cursor {
+        Some(since) => format!("({}) AND updated > \"{}\"", base_filter, since),
+        None => base_filter,
+    };
+
+    jira.list_issue_refs_for_jql(&probe_jql)
+        .map(|refs| refs.len())
+        .unwrap_or(0)
+}
+
+/// Same as [`sync_issues`] but, when `resume_from` names a workspace already
+/// in progress, skips issues cached in that prior run and continues with its
+/// remaining budget instead of starting over from the top.
+pub fn sync_issues_resumable(
+    jira: &JiraClient,
+    cache: &Arc<InMemoryCache>,
+    workspaces: &[(String, String)],
+    budget: usize,
+    force_full: bool,
+    resume_from: Option<SyncCheckpoint>,
 ) -> SyncResult {
     let mut result = SyncResult {
         issues_cached: 0,
         issues_skipped: 0,
         errors: Vec::new(),
+        evicted: 0,
     };
 
     if budget == 0 {
@@ -62,127 +254,529 @@ pub fn sync_issues(
         return result;
     }
 
+    let mut already_cached_this_run: Vec<String> = Vec::new();
+    let mut effective_budget = budget;
+    if let Some(checkpoint) = &resume_from {
+        logging::info(format!(
+            "resuming sync for workspace {} with {} issues already cached this run",
+            checkpoint.project,
+            checkpoint.cached_this_run.len()
+        ));
+        already_cached_this_run = checkpoint.cached_this_run.clone();
+        effective_budget = checkpoint.remaining_budget.min(budget);
+    }
+
     for (workspace, base_jql) in workspaces {
-        let cursor = if force_full {
-            None
-        } else {
-            cache.get_sync_cursor(workspace)
-        };
+        cache.set_sync_checkpoint(&SyncCheckpoint {
+            project: workspace.clone(),
+            start_at: 0,
+            remaining_budget: effective_budget.saturating_sub(result.issues_cached),
+            cached_this_run: already_cached_this_run.clone(),
+        });
+        let remaining_budget = effective_budget.saturating_sub(result.issues_cached);
+        let outcome = sync_one_workspace(
+            jira,
+            cache,
+            workspace,
+            base_jql,
+            force_full,
+            remaining_budget,
+            &already_cached_this_run,
+        );
 
-        let (base_filter, base_order) = split_jql_order_by(base_jql);
-        let jql = match &cursor {
-            Some(since) => {
-                logging::info(format!(
-                    "incremental sync for workspace {} since {}",
-                    workspace, since
-                ));
-                let order_clause =
-                    base_order.unwrap_or_else(|| "ORDER BY updated DESC".to_string());
-                format!(
-                    "({}) AND updated > \"{}\" {}",
-                    base_filter, since, order_clause
-                )
+        match outcome.error {
+            Some(error) => {
+                result.errors.push(error);
+                continue;
             }
-            None => {
-                logging::info(format!("initial full sync for workspace {}", workspace));
-                base_jql.trim().to_string()
+            None if outcome.skipped => {
+                result.issues_skipped += 1;
+                continue;
+            }
+            None => {}
+        }
+
+        result.issues_cached += outcome.issues_cached;
+        already_cached_this_run.extend(outcome.newly_cached_keys);
+
+        cache.set_sync_checkpoint(&SyncCheckpoint {
+            project: workspace.clone(),
+            start_at: 0,
+            remaining_budget: effective_budget.saturating_sub(result.issues_cached),
+            cached_this_run: already_cached_this_run.clone(),
+        });
+
+        if result.issues_cached >= effective_budget {
+            break;
+        }
+    }
+
+    if result.errors.is_empty() {
+        cache.clear_sync_checkpoint();
+    }
+
+    result
+}
+
+/// Periodic (or `force_full`-triggered) correction pass for drift that
+/// incremental sync can't see: an issue deleted in Jira, or edited out of a
+/// workspace's JQL scope, never shows up as an `updated` change, so it would
+/// otherwise linger in the cache forever. Runs each workspace's bare
+/// `base_filter` (ignoring any cursor) through `list_issue_refs_for_jql`,
+/// diffs the returned key set against [`InMemoryCache::get_project_issues_snapshot`],
+/// and evicts anything cached that's no longer in scope via
+/// [`InMemoryCache::remove_issue_everywhere`] and
+/// [`InMemoryCache::tombstone_issue`].
+///
+/// The full diff is the expensive part, so each workspace's sorted
+/// key+updated digest is compared against the one
+/// [`InMemoryCache::get_reconcile_digest`] stored last time; when they match,
+/// nothing in that workspace's JQL scope has shifted and the diff is skipped
+/// entirely. `force_full` bypasses the digest short-circuit, for callers that
+/// want a guaranteed pass regardless of what changed.
+///
+/// # Errors
+/// Per-workspace failures are collected into `result.errors`; `result.evicted`
+/// reports the total number of issues dropped so callers can log churn.
+pub fn reconcile_projects(
+    jira: &JiraClient,
+    cache: &InMemoryCache,
+    workspaces: &[(String, String)],
+    force_full: bool,
+) -> SyncResult {
+    let workspace_names: Vec<String> = workspaces.iter().map(|(workspace, _)| workspace.clone()).collect();
+    let task_id = cache.enqueue_sync_task(SyncTaskKind::Reconcile, workspace_names);
+    cache.start_sync_task(task_id);
+
+    let mut result = SyncResult {
+        issues_cached: 0,
+        issues_skipped: 0,
+        errors: Vec::new(),
+        evicted: 0,
+    };
+
+    for (workspace, base_jql) in workspaces {
+        let (base_filter, _) = split_jql_order_by(base_jql);
+        let live_refs = match jira.list_issue_refs_for_jql(&base_filter) {
+            Ok(refs) => refs,
+            Err(err) => {
+                result
+                    .errors
+                    .push(format!("reconcile failed for workspace {}: {}", workspace, err));
+                continue;
             }
         };
 
-        let page_size = budget.min(100);
-
-        match jira.search_issues_bulk(&jql, page_size) {
-            Ok(issues) => {
-                let latest_refs: Vec<_> = issues
-                    .iter()
-                    .map(|issue| crate::jira::IssueRef {
-                        key: issue.key.clone(),
-                        updated: issue.updated.clone(),
-                    })
-                    .collect();
-
-                if cursor.is_none() {
-                    cache.upsert_workspace_issues(workspace, latest_refs);
-                } else {
-                    let mut merged = cache
-                        .get_workspace_issues_snapshot(workspace)
-                        .map(|snapshot| snapshot.issues)
-                        .unwrap_or_default();
-
-                    for new_ref in latest_refs {
-                        if let Some(existing) =
-                            merged.iter_mut().find(|item| item.key == new_ref.key)
-                        {
-                            existing.updated = new_ref.updated.clone();
-                        } else {
-                            merged.push(new_ref);
-                        }
-                    }
-
-                    merged.sort_by(|a, b| a.key.cmp(&b.key));
-                    cache.upsert_workspace_issues(workspace, merged);
-                }
+        let digest = reconcile_digest(&live_refs);
+        if !force_full && cache.get_reconcile_digest(workspace).as_deref() == Some(digest.as_str()) {
+            logging::info(format!(
+                "reconcile for workspace {}: digest unchanged, skipping diff",
+                workspace
+            ));
+            result.issues_skipped += 1;
+            continue;
+        }
 
-                if issues.is_empty() {
-                    logging::info(format!("sync for workspace {}: no changes", workspace));
-                    result.issues_skipped += 1;
-                    continue;
-                }
+        let live_keys: std::collections::HashSet<&str> =
+            live_refs.iter().map(|issue_ref| issue_ref.key.as_str()).collect();
+        let cached_keys = cache
+            .get_project_issues_snapshot(workspace)
+            .map(|snapshot| snapshot.issues)
+            .unwrap_or_default();
 
-                let remaining_budget = budget.saturating_sub(result.issues_cached);
-                let count = issues.len().min(remaining_budget);
-
-                let to_cache: Vec<_> = issues
-                    .iter()
-                    .take(count)
-                    .map(|issue| {
-                        let markdown = render_issue_markdown(issue).into_bytes();
-                        (issue.key.clone(), markdown, issue.updated.clone())
-                    })
-                    .collect();
-
-                let sidecars: Vec<_> = issues
-                    .iter()
-                    .take(count)
-                    .map(|issue| {
-                        (
-                            issue.key.clone(),
-                            render_issue_comments_markdown(issue).into_bytes(),
-                            issue.updated.clone(),
-                        )
-                    })
-                    .collect();
-
-                let cached = cache.upsert_issues_batch(&to_cache);
-                let _ = cache.upsert_issue_sidecars_batch(&sidecars);
-                result.issues_cached += cached;
-
-                if let Some(latest) = issues.first().and_then(|i| i.updated.as_ref()) {
-                    cache.set_sync_cursor(workspace, latest);
-                    logging::info(format!(
-                        "updated sync cursor for workspace {} to {}",
-                        workspace, latest
-                    ));
-                }
+        let now = unix_epoch_seconds();
+        let mut evicted = 0;
+        for issue_ref in &cached_keys {
+            if live_keys.contains(issue_ref.key.as_str()) {
+                continue;
+            }
+            cache.remove_issue_everywhere(workspace, &issue_ref.key);
+            cache.tombstone_issue(workspace, &issue_ref.key, now);
+            evicted += 1;
+        }
 
-                logging::info(format!(
-                    "sync for workspace {}: cached {} issues",
-                    workspace, cached
-                ));
+        cache.upsert_project_issues(workspace, live_refs);
+        cache.set_reconcile_digest(workspace, &digest);
+
+        let forgotten = cache.gc_tombstones(workspace, TOMBSTONE_RETENTION_SECS, now);
+        if forgotten > 0 {
+            logging::info(format!(
+                "reconcile for workspace {}: garbage collected {} tombstones older than {} days",
+                workspace,
+                forgotten,
+                TOMBSTONE_RETENTION_SECS / 86_400
+            ));
+        }
+
+        if evicted > 0 {
+            logging::info(format!(
+                "reconcile for workspace {}: evicted {} issues no longer in scope",
+                workspace, evicted
+            ));
+        }
+        result.evicted += evicted;
+    }
 
-                if result.issues_cached >= budget {
-                    break;
+    let status = if !result.errors.is_empty() {
+        if result.evicted > 0 || result.issues_skipped > 0 {
+            SyncTaskStatus::Partial
+        } else {
+            SyncTaskStatus::Failed
+        }
+    } else {
+        SyncTaskStatus::Succeeded
+    };
+    cache.finish_sync_task(task_id, status, result.errors.clone());
+
+    result
+}
+
+/// Content digest over a workspace's full set of issue refs, sorted by key so
+/// the result is stable regardless of the order Jira returned them in. Used
+/// by [`reconcile_projects`] to skip the expensive diff when a workspace's
+/// JQL scope hasn't shifted since the last pass.
+fn reconcile_digest(refs: &[crate::jira::IssueRef]) -> String {
+    let mut sorted: Vec<&crate::jira::IssueRef> = refs.iter().collect();
+    sorted.sort_by(|a, b| a.key.cmp(&b.key));
+
+    let mut hasher = blake3::Hasher::new();
+    for issue_ref in sorted {
+        hasher.update(issue_ref.key.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(issue_ref.updated.as_deref().unwrap_or("").as_bytes());
+        hasher.update(b"\n");
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+fn unix_epoch_seconds() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_else(|_| {
+            logging::warn("system clock before unix epoch; using fallback timestamp 0");
+            0
+        })
+}
+
+/// Fetches, merges, and caches one workspace's pending issues against a
+/// fixed `workspace_budget`, shared by [`sync_issues_resumable`]'s
+/// sequential loop and [`sync_issues_parallel`]'s worker threads. Neither
+/// reads nor writes a [`SyncCheckpoint`] — checkpointing stays the caller's
+/// responsibility, since only the sequential, resumable path needs it.
+///
+/// Paginates via [`JiraClient::search_issues_bulk_from`]'s `start_at`
+/// continuation, requesting successive pages until either
+/// `workspace_budget` is exhausted or a page comes back smaller than
+/// requested (the workspace's results are drained) — so a budget larger
+/// than one page's worth (100 issues) is actually honored in a single call,
+/// instead of silently stopping after the first page.
+fn sync_one_workspace(
+    jira: &JiraClient,
+    cache: &InMemoryCache,
+    workspace: &str,
+    base_jql: &str,
+    force_full: bool,
+    workspace_budget: usize,
+    already_cached_this_run: &[String],
+) -> WorkspaceSyncOutcome {
+    let cursor = if force_full {
+        None
+    } else {
+        cache.get_sync_cursor(workspace)
+    };
+
+    let (base_filter, base_order) = split_jql_order_by(base_jql);
+    let jql = match &cursor {
+        Some(since) => {
+            logging::info(format!(
+                "incremental sync for workspace {} since {}",
+                workspace, since
+            ));
+            let order_clause = base_order.unwrap_or_else(|| "ORDER BY updated DESC".to_string());
+            format!(
+                "({}) AND updated > \"{}\" {}",
+                base_filter, since, order_clause
+            )
+        }
+        None => {
+            logging::info(format!("initial full sync for workspace {}", workspace));
+            base_jql.trim().to_string()
+        }
+    };
+
+    let mut all_refs: Vec<crate::jira::IssueRef> = Vec::new();
+    let mut local_already_cached: Vec<String> = already_cached_this_run.to_vec();
+    let mut newly_cached_keys: Vec<String> = Vec::new();
+    let mut total_cached = 0usize;
+    let mut newest_cached_updated: Option<String> = None;
+    let mut start_at = 0usize;
+    let mut pages = 0usize;
+
+    loop {
+        let remaining_budget = workspace_budget.saturating_sub(total_cached);
+        if remaining_budget == 0 {
+            break;
+        }
+        let page_size = remaining_budget.min(100);
+
+        let page = match jira.search_issues_bulk_from(&jql, page_size, start_at) {
+            Ok(page) => page,
+            Err(err) => {
+                if start_at == 0 {
+                    let msg = format!("sync failed for workspace {}: {}", workspace, err);
+                    logging::warn(&msg);
+                    return WorkspaceSyncOutcome {
+                        issues_cached: 0,
+                        newly_cached_keys: Vec::new(),
+                        skipped: false,
+                        error: Some(msg),
+                    };
                 }
+                logging::warn(format!(
+                    "sync for workspace {}: page at offset {} failed, keeping {} issues already cached this run: {}",
+                    workspace, start_at, total_cached, err
+                ));
+                break;
             }
-            Err(err) => {
-                let msg = format!("sync failed for workspace {}: {}", workspace, err);
-                logging::warn(&msg);
-                result.errors.push(msg);
+        };
+
+        let page_len = page.len();
+        if page_len == 0 {
+            break;
+        }
+        pages += 1;
+
+        all_refs.extend(page.iter().map(|issue| crate::jira::IssueRef {
+            key: issue.key.clone(),
+            updated: issue.updated.clone(),
+        }));
+
+        let pending: Vec<_> = page
+            .iter()
+            .filter(|issue| !local_already_cached.contains(&issue.key))
+            .collect();
+        let count = pending.len().min(remaining_budget);
+
+        let to_cache: Vec<_> = pending
+            .iter()
+            .take(count)
+            .map(|issue| {
+                let markdown = render_issue_markdown(issue).into_bytes();
+                (issue.key.clone(), markdown, issue.updated.clone())
+            })
+            .collect();
+
+        let sidecars: Vec<_> = pending
+            .iter()
+            .take(count)
+            .map(|issue| {
+                (
+                    issue.key.clone(),
+                    render_issue_comments_markdown(issue).into_bytes(),
+                    issue.updated.clone(),
+                )
+            })
+            .collect();
+
+        let cached = cache.upsert_issues_batch(&to_cache);
+        let _ = cache.upsert_issue_sidecars_batch(&sidecars);
+        let page_new_keys: Vec<String> = to_cache.iter().map(|(key, _, _)| key.clone()).collect();
+        local_already_cached.extend(page_new_keys.iter().cloned());
+        newly_cached_keys.extend(page_new_keys);
+        total_cached += cached;
+
+        if let Some(updated) = page.first().and_then(|issue| issue.updated.clone()) {
+            if is_newer_updated(&newest_cached_updated, &Some(updated.clone())) {
+                newest_cached_updated = Some(updated);
+            }
+        }
+
+        start_at += page_len;
+
+        if page_len < page_size {
+            break;
+        }
+    }
+
+    if all_refs.is_empty() {
+        logging::info(format!("sync for workspace {}: no changes", workspace));
+        return WorkspaceSyncOutcome {
+            issues_cached: 0,
+            newly_cached_keys: Vec::new(),
+            skipped: true,
+            error: None,
+        };
+    }
+
+    if cursor.is_none() {
+        cache.upsert_project_issues(workspace, all_refs);
+    } else {
+        let mut merged = cache
+            .get_project_issues_snapshot(workspace)
+            .map(|snapshot| snapshot.issues)
+            .unwrap_or_default();
+
+        for new_ref in all_refs {
+            match merged.iter_mut().find(|item| item.key == new_ref.key) {
+                Some(existing) if is_newer_updated(&existing.updated, &new_ref.updated) => {
+                    existing.updated = new_ref.updated;
+                }
+                Some(_) => {}
+                None => merged.push(new_ref),
             }
         }
+
+        merged.sort_by(|a, b| a.key.cmp(&b.key));
+        cache.upsert_project_issues(workspace, merged);
     }
 
-    result
+    if let Some(latest) = &newest_cached_updated {
+        cache.set_sync_cursor(workspace, latest);
+        logging::info(format!(
+            "updated sync cursor for workspace {} to {}",
+            workspace, latest
+        ));
+    }
+
+    logging::info(format!(
+        "sync for workspace {}: cached {} issues across {} page(s)",
+        workspace, total_cached, pages
+    ));
+
+    WorkspaceSyncOutcome {
+        issues_cached: total_cached,
+        newly_cached_keys,
+        skipped: false,
+        error: None,
+    }
+}
+
+/// Decides whether `candidate` should replace `current` as an issue's
+/// `updated` timestamp during incremental merge: true if `candidate` is
+/// parseable and newer, or if `current` is absent and `candidate` isn't.
+/// Falls back to plain lexical comparison when either string doesn't parse
+/// as a timestamp [`parse_jira_timestamp`] understands, rather than refusing
+/// to ever update a field Jira returned in an unfamiliar format.
+fn is_newer_updated(current: &Option<String>, candidate: &Option<String>) -> bool {
+    let (Some(current), Some(candidate)) = (current, candidate) else {
+        return current.is_none() && candidate.is_some();
+    };
+
+    match (parse_jira_timestamp(current), parse_jira_timestamp(candidate)) {
+        (Some(current_secs), Some(candidate_secs)) => candidate_secs > current_secs,
+        _ => candidate.as_str() > current.as_str(),
+    }
+}
+
+/// Parses a Jira `updated` timestamp (`2024-05-01T12:34:56.000+0000`, or the
+/// `Z`/`+HH:MM` offset variants) into unix seconds, without a chrono
+/// dependency — this crate deliberately avoids one; see
+/// `cache::persistent::iso8601_version_id` for the same tradeoff elsewhere.
+/// Returns `None` for anything that doesn't match, so callers can fall back
+/// to lexical comparison instead of guessing.
+pub(crate) fn parse_jira_timestamp(raw: &str) -> Option<i64> {
+    let bytes = raw.as_bytes();
+    if bytes.len() < 19 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[10] != b'T' {
+        return None;
+    }
+    let digits = |s: &str| s.parse::<i64>().ok();
+
+    let year = digits(raw.get(0..4)?)?;
+    let month = digits(raw.get(5..7)?)?;
+    let day = digits(raw.get(8..10)?)?;
+    let hour = digits(raw.get(11..13)?)?;
+    let minute = digits(raw.get(14..16)?)?;
+    let second = digits(raw.get(17..19)?)?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let offset_secs = parse_utc_offset(&raw[19..]).unwrap_or(0);
+    let days = days_from_civil(year, month, day);
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second - offset_secs)
+}
+
+/// Parses the timezone marker trailing an optional `.fff` fraction: `Z`,
+/// `+HHMM`, `+HH:MM`, or their `-` counterparts. An absent suffix is treated
+/// as UTC (offset `0`); anything else unrecognized returns `None`.
+fn parse_utc_offset(suffix: &str) -> Option<i64> {
+    if suffix.is_empty() {
+        return Some(0);
+    }
+    let marker_at = suffix.find(['+', '-', 'Z'])?;
+    let marker = &suffix[marker_at..];
+    if marker == "Z" {
+        return Some(0);
+    }
+
+    let (sign, rest) = match marker.as_bytes()[0] {
+        b'+' => (1, &marker[1..]),
+        b'-' => (-1, &marker[1..]),
+        _ => return None,
+    };
+    let rest: String = rest.chars().filter(|c| *c != ':').collect();
+    if rest.len() != 4 {
+        return None;
+    }
+    let hours: i64 = rest[0..2].parse().ok()?;
+    let minutes: i64 = rest[2..4].parse().ok()?;
+    Some(sign * (hours * 3_600 + minutes * 60))
+}
+
+/// Howard Hinnant's `days_from_civil`: maps a proleptic-Gregorian calendar
+/// date to a day count relative to the unix epoch (1970-01-01 = day 0).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Renders `then` (unix seconds, as returned by [`parse_jira_timestamp`])
+/// relative to `now` as a short human phrase (`"just now"`, `"5 minutes
+/// ago"`, `"3 days ago"`), without a chrono dependency — same tradeoff as
+/// `parse_jira_timestamp` above. An instant after `now` renders as
+/// `"just now"` rather than a negative duration; anything a year or more
+/// back is counted in whole years.
+///
+/// `render_issue_markdown` in `src/render.rs` does not call this: rendered
+/// markdown shows the raw `updated` string, and `IssueRef` carries no
+/// priority or component fields.
+pub(crate) fn humanize_relative(then: i64, now: i64) -> String {
+    let delta = now.saturating_sub(then);
+    if delta < 0 {
+        return "just now".to_string();
+    }
+
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const MONTH: i64 = 30 * DAY;
+    const YEAR: i64 = 365 * DAY;
+
+    let (amount, unit) = if delta < MINUTE {
+        return "just now".to_string();
+    } else if delta < HOUR {
+        (delta / MINUTE, "minute")
+    } else if delta < DAY {
+        (delta / HOUR, "hour")
+    } else if delta < MONTH {
+        (delta / DAY, "day")
+    } else if delta < YEAR {
+        (delta / MONTH, "month")
+    } else {
+        (delta / YEAR, "year")
+    };
+
+    if amount == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{amount} {unit}s ago")
+    }
 }
 
 fn split_jql_order_by(jql: &str) -> (String, Option<String>) {
@@ -204,7 +798,73 @@ fn split_jql_order_by(jql: &str) -> (String, Option<String>) {
 
 #[cfg(test)]
 mod tests {
-    use super::split_jql_order_by;
+    use super::{humanize_relative, is_newer_updated, parse_jira_timestamp, reconcile_digest, split_jql_order_by};
+    use crate::jira::IssueRef;
+
+    #[test]
+    fn parse_jira_timestamp_handles_z_and_offset_suffixes() {
+        let z = parse_jira_timestamp("2024-05-01T12:00:00.000Z").expect("parses");
+        let offset = parse_jira_timestamp("2024-05-01T13:00:00.000+0100").expect("parses");
+        assert_eq!(z, offset, "13:00+0100 is the same instant as 12:00Z");
+    }
+
+    #[test]
+    fn parse_jira_timestamp_rejects_unfamiliar_format() {
+        assert!(parse_jira_timestamp("not-a-timestamp").is_none());
+    }
+
+    #[test]
+    fn is_newer_updated_prefers_the_later_parsed_timestamp() {
+        let older = Some("2024-05-01T12:00:00.000+0000".to_string());
+        let newer = Some("2024-05-02T12:00:00.000+0000".to_string());
+        assert!(is_newer_updated(&older, &newer));
+        assert!(!is_newer_updated(&newer, &older));
+    }
+
+    #[test]
+    fn is_newer_updated_falls_back_to_lexical_comparison() {
+        let current = Some("not-a-timestamp-a".to_string());
+        let candidate = Some("not-a-timestamp-b".to_string());
+        assert!(is_newer_updated(&current, &candidate));
+    }
+
+    #[test]
+    fn humanize_relative_picks_the_coarsest_sensible_unit() {
+        let now = 1_700_000_000;
+        assert_eq!(humanize_relative(now - 30, now), "just now");
+        assert_eq!(humanize_relative(now - 5 * 60, now), "5 minutes ago");
+        assert_eq!(humanize_relative(now - 60 * 60, now), "1 hour ago");
+        assert_eq!(humanize_relative(now - 3 * 86_400, now), "3 days ago");
+    }
+
+    #[test]
+    fn humanize_relative_treats_future_instants_as_just_now() {
+        let now = 1_700_000_000;
+        assert_eq!(humanize_relative(now + 60, now), "just now");
+    }
+
+    #[test]
+    fn reconcile_digest_is_stable_regardless_of_input_order() {
+        let a = vec![
+            IssueRef { key: "PROJ-1".to_string(), updated: Some("u1".to_string()) },
+            IssueRef { key: "PROJ-2".to_string(), updated: Some("u2".to_string()) },
+        ];
+        let b = vec![
+            IssueRef { key: "PROJ-2".to_string(), updated: Some("u2".to_string()) },
+            IssueRef { key: "PROJ-1".to_string(), updated: Some("u1".to_string()) },
+        ];
+        assert_eq!(reconcile_digest(&a), reconcile_digest(&b));
+    }
+
+    #[test]
+    fn reconcile_digest_changes_when_an_issue_is_removed() {
+        let before = vec![
+            IssueRef { key: "PROJ-1".to_string(), updated: Some("u1".to_string()) },
+            IssueRef { key: "PROJ-2".to_string(), updated: Some("u2".to_string()) },
+        ];
+        let after = vec![IssueRef { key: "PROJ-1".to_string(), updated: Some("u1".to_string()) }];
+        assert_ne!(reconcile_digest(&before), reconcile_digest(&after));
+    }
 
     #[test]
     fn split_jql_order_by_extracts_order_clause() {
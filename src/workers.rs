@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+use crate::logging;
+
+/// Opaque identifier for a background worker, assigned in registration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WorkerId(pub u64);
+
+impl std::fmt::Display for WorkerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerState {
+    Active,
+    Idle,
+    Dead,
+}
+
+impl WorkerState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            WorkerState::Active => "active",
+            WorkerState::Idle => "idle",
+            WorkerState::Dead => "dead",
+        }
+    }
+}
+
+/// Commands a worker loop should poll for between units of work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorkerCommand {
+    Pause,
+    Resume,
+    Cancel,
+}
+
+/// Shared, observable status for one background worker.
+#[derive(Debug)]
+pub struct WorkerHandle {
+    pub id: WorkerId,
+    pub kind: String,
+    state: Mutex<WorkerState>,
+    processed: AtomicUsize,
+    total: AtomicUsize,
+    last_error: Mutex<Option<String>>,
+    commands: Sender<WorkerCommand>,
+}
+
+impl WorkerHandle {
+    pub fn set_state(&self, state: WorkerState) {
+        *self.state.lock_or_recover("worker state") = state;
+    }
+
+    pub fn state(&self) -> WorkerState {
+        *self.state.lock_or_recover("worker state")
+    }
+
+    pub fn set_progress(&self, processed: usize, total: usize) {
+        self.processed.store(processed, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn set_last_error(&self, error: Option<String>) {
+        *self.last_error.lock_or_recover("worker last_error") = error;
+    }
+
+    pub fn send(&self, command: WorkerCommand) {
+        let _ = self.commands.send(command);
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "id": self.id.0,
+            "kind": self.kind,
+            "state": self.state().as_str(),
+            "progress": {
+                "processed": self.processed.load(Ordering::Relaxed),
+                "total": self.total.load(Ordering::Relaxed),
+            },
+            "last_error": self.last_error.lock_or_recover("worker last_error").clone(),
+        })
+    }
+}
+
+/// Owns every background worker's status and control channel.
+#[derive(Debug, Default)]
+pub struct WorkerManager {
+    next_id: AtomicU64,
+    workers: Mutex<HashMap<WorkerId, Arc<WorkerHandle>>>,
+}
+
+impl WorkerManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new worker and returns its handle and the command receiver
+    /// the worker loop should poll between steps.
+    pub fn register(&self, kind: impl Into<String>) -> (Arc<WorkerHandle>, Receiver<WorkerCommand>) {
+        let id = WorkerId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        let (tx, rx) = mpsc::channel();
+        let handle = Arc::new(WorkerHandle {
+            id,
+            kind: kind.into(),
+            state: Mutex::new(WorkerState::Idle),
+            processed: AtomicUsize::new(0),
+            total: AtomicUsize::new(0),
+            last_error: Mutex::new(None),
+            commands: tx,
+        });
+        self.workers
+            .lock_or_recover("worker manager")
+            .insert(id, Arc::clone(&handle));
+        (handle, rx)
+    }
+
+    pub fn worker(&self, id: WorkerId) -> Option<Arc<WorkerHandle>> {
+        self.workers.lock_or_recover("worker manager").get(&id).cloned()
+    }
+
+    pub fn worker_by_str(&self, id: &str) -> Option<Arc<WorkerHandle>> {
+        let id: u64 = id.parse().ok()?;
+        self.worker(WorkerId(id))
+    }
+
+    pub fn ids(&self) -> Vec<WorkerId> {
+        let mut ids: Vec<_> = self.workers.lock_or_recover("worker manager").keys().copied().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Renders `.sync_meta/workers/index.jsonl`, one JSON object per line.
+    pub fn index_jsonl(&self) -> Vec<u8> {
+        let mut out = String::new();
+        for id in self.ids() {
+            if let Some(worker) = self.worker(id) {
+                out.push_str(&worker.to_json().to_string());
+                out.push('\n');
+            }
+        }
+        out.into_bytes()
+    }
+}
+
+trait MutexExt<T> {
+    fn lock_or_recover(&self, name: &'static str) -> std::sync::MutexGuard<'_, T>;
+}
+
+impl<T> MutexExt<T> for Mutex<T> {
+    fn lock_or_recover(&self, name: &'static str) -> std::sync::MutexGuard<'_, T> {
+        match self.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                logging::warn(format!("recovering poisoned mutex: {}", name));
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_workers_with_increasing_ids() {
+        let manager = WorkerManager::new();
+        let (first, _rx1) = manager.register("sync");
+        let (second, _rx2) = manager.register("scrub");
+        assert_eq!(first.id, WorkerId(0));
+        assert_eq!(second.id, WorkerId(1));
+        assert_eq!(manager.ids(), vec![WorkerId(0), WorkerId(1)]);
+    }
+
+    #[test]
+    fn index_jsonl_reflects_progress_and_state() {
+        let manager = WorkerManager::new();
+        let (worker, _rx) = manager.register("sync");
+        worker.set_state(WorkerState::Active);
+        worker.set_progress(3, 10);
+
+        let rendered = String::from_utf8(manager.index_jsonl()).expect("utf8");
+        assert!(rendered.contains("\"state\":\"active\""));
+        assert!(rendered.contains("\"processed\":3"));
+    }
+
+    #[test]
+    fn control_commands_reach_the_receiver() {
+        let manager = WorkerManager::new();
+        let (worker, rx) = manager.register("sync");
+        worker.send(WorkerCommand::Pause);
+        assert_eq!(rx.recv().expect("command"), WorkerCommand::Pause);
+    }
+}
@@ -0,0 +1,179 @@
+use serde::Deserialize;
+
+use crate::jira::JiraClient;
+
+/// Editable front-matter fields on an issue's `.md` file. Anything else in
+/// the rendered markdown (description body) is treated as free text.
+#[derive(Debug, Clone, Deserialize, Default, PartialEq)]
+pub struct IssueFrontMatter {
+    pub summary: Option<String>,
+    pub assignee: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum WriteBackError {
+    /// The buffer has no `---`-delimited front-matter block, or it doesn't parse.
+    InvalidFrontMatter(String),
+    /// `status` names a transition Jira doesn't expose for this issue.
+    UnknownTransition(String),
+    /// The Jira API call itself failed.
+    JiraError(String),
+}
+
+/// Splits `---\n<yaml>\n---\n<body>` into its front-matter and body halves.
+pub fn parse_front_matter(bytes: &[u8]) -> Result<(IssueFrontMatter, String), WriteBackError> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut parts = text.splitn(3, "---\n");
+
+    // splitn on a string starting with "---\n" yields ["", yaml, body...].
+    let Some(leading) = parts.next() else {
+        return Err(WriteBackError::InvalidFrontMatter(
+            "missing front-matter block".to_string(),
+        ));
+    };
+    if !leading.trim().is_empty() {
+        return Err(WriteBackError::InvalidFrontMatter(
+            "content before front-matter delimiter".to_string(),
+        ));
+    }
+
+    let Some(yaml) = parts.next() else {
+        return Err(WriteBackError::InvalidFrontMatter(
+            "missing closing --- delimiter".to_string(),
+        ));
+    };
+    let body = parts.next().unwrap_or("").to_string();
+
+    let front_matter: IssueFrontMatter = serde_yaml::from_str(yaml)
+        .map_err(|err| WriteBackError::InvalidFrontMatter(err.to_string()))?;
+
+    Ok((front_matter, body))
+}
+
+/// One field-level change to push back to Jira.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldChange {
+    Summary(String),
+    Assignee(String),
+    Labels(Vec<String>),
+    Priority(String),
+    Status(String),
+}
+
+/// Computes the minimal set of changes between the edited front-matter and
+/// the last-known-good issue snapshot.
+pub fn diff_front_matter(edited: &IssueFrontMatter, original: &IssueFrontMatter) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    if let Some(summary) = &edited.summary {
+        if original.summary.as_ref() != Some(summary) {
+            changes.push(FieldChange::Summary(summary.clone()));
+        }
+    }
+    if let Some(assignee) = &edited.assignee {
+        if original.assignee.as_ref() != Some(assignee) {
+            changes.push(FieldChange::Assignee(assignee.clone()));
+        }
+    }
+    if let Some(labels) = &edited.labels {
+        if original.labels.as_ref() != Some(labels) {
+            changes.push(FieldChange::Labels(labels.clone()));
+        }
+    }
+    if let Some(priority) = &edited.priority {
+        if original.priority.as_ref() != Some(priority) {
+            changes.push(FieldChange::Priority(priority.clone()));
+        }
+    }
+    if let Some(status) = &edited.status {
+        if original.status.as_ref() != Some(status) {
+            changes.push(FieldChange::Status(status.clone()));
+        }
+    }
+
+    changes
+}
+
+/// Pushes a diffed set of field changes back to Jira, issuing a plain field
+/// update for everything except `status`, which goes through a transition
+/// lookup + POST instead.
+pub fn push_changes(
+    jira: &JiraClient,
+    issue_key: &str,
+    changes: &[FieldChange],
+) -> Result<(), WriteBackError> {
+    for change in changes {
+        match change {
+            FieldChange::Status(status) => {
+                let transition_id = jira
+                    .find_transition_id(issue_key, status)
+                    .map_err(|err| WriteBackError::JiraError(err.to_string()))?
+                    .ok_or_else(|| WriteBackError::UnknownTransition(status.clone()))?;
+                jira.transition_issue(issue_key, &transition_id)
+                    .map_err(|err| WriteBackError::JiraError(err.to_string()))?;
+            }
+            FieldChange::Summary(value) => jira
+                .update_issue_field(issue_key, "summary", value)
+                .map_err(|err| WriteBackError::JiraError(err.to_string()))?,
+            FieldChange::Assignee(value) => jira
+                .update_issue_field(issue_key, "assignee", value)
+                .map_err(|err| WriteBackError::JiraError(err.to_string()))?,
+            FieldChange::Priority(value) => jira
+                .update_issue_field(issue_key, "priority", value)
+                .map_err(|err| WriteBackError::JiraError(err.to_string()))?,
+            FieldChange::Labels(values) => jira
+                .update_issue_labels(issue_key, values)
+                .map_err(|err| WriteBackError::JiraError(err.to_string()))?,
+        }
+    }
+    Ok(())
+}
+
+/// Posts a new comment body to an issue.
+pub fn post_comment(jira: &JiraClient, issue_key: &str, body: &str) -> Result<(), WriteBackError> {
+    jira.add_comment(issue_key, body)
+        .map_err(|err| WriteBackError::JiraError(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_front_matter_and_body() {
+        let bytes = b"---\nsummary: Fix the bug\nstatus: In Progress\n---\nDescription body.\n";
+        let (front_matter, body) = parse_front_matter(bytes).expect("parses");
+        assert_eq!(front_matter.summary.as_deref(), Some("Fix the bug"));
+        assert_eq!(front_matter.status.as_deref(), Some("In Progress"));
+        assert_eq!(body, "Description body.\n");
+    }
+
+    #[test]
+    fn rejects_missing_front_matter() {
+        let bytes = b"just a body, no front matter\n";
+        assert!(matches!(
+            parse_front_matter(bytes),
+            Err(WriteBackError::InvalidFrontMatter(_))
+        ));
+    }
+
+    #[test]
+    fn diffs_only_changed_fields() {
+        let original = IssueFrontMatter {
+            summary: Some("Old summary".to_string()),
+            status: Some("To Do".to_string()),
+            ..Default::default()
+        };
+        let edited = IssueFrontMatter {
+            summary: Some("Old summary".to_string()),
+            status: Some("Done".to_string()),
+            ..Default::default()
+        };
+
+        let changes = diff_front_matter(&edited, &original);
+        assert_eq!(changes, vec![FieldChange::Status("Done".to_string())]);
+    }
+}